@@ -6,15 +6,22 @@ use std::{
     ops::Deref,
 };
 
-use crate::Type;
+use crate::{IntegerKind, Type};
 
 pub struct Field<T> {
-    pub name:        &'static str,
-    pub tp:          Type,
-    pub type_name:   &'static str,
-    pub parent_name: &'static str,
-    pub optional:    bool,
-    pub _p:          PhantomData<fn() -> T>,
+    pub name:         &'static str,
+    pub tp:           Type,
+    pub type_name:    &'static str,
+    pub parent_name:  &'static str,
+    pub optional:     bool,
+    /// Width and signedness of `Type::Integer` fields
+    pub integer_kind: Option<IntegerKind>,
+    /// `RandomReflected` can generate the value. See `ReflectedValue::RANDOM`
+    pub random:       bool,
+    /// Position in `Reflected::fields()`. Fields excluded with `skip_fields`
+    /// come after all other fields
+    pub index:        usize,
+    pub _p:           PhantomData<fn() -> T>,
 }
 
 impl<T> Clone for Field<T> {
@@ -34,14 +41,32 @@ impl<T> Field<T> {
         self.name.contains("_id")
     }
 
+    /// Same field without owner type. Used in `StructType`
+    pub const fn erased(&self) -> Field<()> {
+        Field {
+            name:         self.name,
+            tp:           self.tp,
+            type_name:    self.type_name,
+            parent_name:  self.parent_name,
+            optional:     self.optional,
+            integer_kind: self.integer_kind,
+            random:       self.random,
+            index:        self.index,
+            _p:           PhantomData,
+        }
+    }
+
     pub fn non_optional(&self) -> Self {
         Self {
-            name:        self.name,
-            tp:          self.get_optional().map_or(self.tp, |op| op.to_non_optional()),
-            type_name:   self.type_name,
-            parent_name: self.parent_name,
-            optional:    false,
-            _p:          PhantomData,
+            name:         self.name,
+            tp:           self.get_optional().map_or(self.tp, |op| op.to_non_optional()),
+            type_name:    self.type_name,
+            parent_name:  self.parent_name,
+            optional:     false,
+            integer_kind: self.integer_kind,
+            random:       self.random,
+            index:        self.index,
+            _p:           PhantomData,
         }
     }
 }
@@ -95,12 +120,15 @@ mod test {
     #[test]
     fn store_in_map() {
         let field: &'static Field<Strekta> = &Field {
-            name:        "",
-            tp:          Type::Float,
-            type_name:   "",
-            parent_name: "",
-            optional:    false,
-            _p:          PhantomData,
+            name:         "",
+            tp:           Type::Float,
+            type_name:    "",
+            parent_name:  "",
+            optional:     false,
+            integer_kind: None,
+            random:       true,
+            index:        0,
+            _p:           PhantomData,
         };
 
         let mut map = HashMap::<&'static Field<Strekta>, String>::default();
@@ -110,12 +138,15 @@ mod test {
     #[test]
     fn debug() {
         let field: &'static Field<Strekta> = &Field {
-            name:        "Name",
-            tp:          Type::Float,
-            type_name:   "f32",
-            parent_name: "SomeStruct",
-            optional:    false,
-            _p:          PhantomData,
+            name:         "Name",
+            tp:           Type::Float,
+            type_name:    "f32",
+            parent_name:  "SomeStruct",
+            optional:     false,
+            integer_kind: None,
+            random:       true,
+            index:        0,
+            _p:           PhantomData,
         };
 
         dbg!(field);
@@ -1,4 +1,7 @@
+use crate::StructType;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum OptionalType {
     Float,
     Integer,
@@ -6,8 +9,22 @@ pub enum OptionalType {
     Date,
     Decimal,
     Bool,
+    Enum,
     Duration,
     DateTime,
+    NaiveDate,
+    NaiveTime,
+    Bytes,
+    List(&'static Type),
+    Map {
+        key:   &'static Type,
+        value: &'static Type,
+    },
+    Struct(&'static StructType),
+    #[cfg(feature = "uuid")]
+    Uuid,
+    #[cfg(feature = "json")]
+    Json,
 }
 
 impl OptionalType {
@@ -19,9 +36,20 @@ impl OptionalType {
             Type::Date => OptionalType::Date,
             Type::Decimal => OptionalType::Decimal,
             Type::Bool => OptionalType::Bool,
+            Type::Enum => OptionalType::Enum,
             Type::Duration => OptionalType::Duration,
             Type::DateTime => OptionalType::DateTime,
-            Type::Optional(_) | Type::Enum => unreachable!(),
+            Type::NaiveDate => OptionalType::NaiveDate,
+            Type::NaiveTime => OptionalType::NaiveTime,
+            Type::Bytes => OptionalType::Bytes,
+            Type::List(element) => OptionalType::List(element),
+            Type::Map { key, value } => OptionalType::Map { key, value },
+            Type::Struct(tp) => OptionalType::Struct(tp),
+            #[cfg(feature = "uuid")]
+            Type::Uuid => OptionalType::Uuid,
+            #[cfg(feature = "json")]
+            Type::Json => OptionalType::Json,
+            Type::Optional(tp) => *tp,
         }
     }
 
@@ -33,13 +61,26 @@ impl OptionalType {
             OptionalType::Date => Type::Date,
             OptionalType::Decimal => Type::Decimal,
             OptionalType::Bool => Type::Bool,
+            OptionalType::Enum => Type::Enum,
             OptionalType::Duration => Type::Duration,
             OptionalType::DateTime => Type::DateTime,
+            OptionalType::NaiveDate => Type::NaiveDate,
+            OptionalType::NaiveTime => Type::NaiveTime,
+            OptionalType::Bytes => Type::Bytes,
+            OptionalType::List(element) => Type::List(element),
+            OptionalType::Map { key, value } => Type::Map { key, value },
+            OptionalType::Struct(tp) => Type::Struct(tp),
+            #[cfg(feature = "uuid")]
+            OptionalType::Uuid => Type::Uuid,
+            #[cfg(feature = "json")]
+            OptionalType::Json => Type::Json,
         }
     }
 }
 
+/// `Uuid` and `Json` variants exist only with their features
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Type {
     Float,
     Integer,
@@ -49,15 +90,79 @@ pub enum Type {
     Bool,
     Enum,
     Duration,
+    /// Timezone aware `DateTime<Utc>` or `DateTime<FixedOffset>`
     DateTime,
+    NaiveDate,
+    NaiveTime,
+    /// `Vec<u8>` or `[u8; N]`
+    Bytes,
+    /// `Vec<T>`, `[T; N]` or `HashSet<T>` of element type. Encoded as JSON
+    /// array of element values: `[1,2]`, `["a",null]`, `[[1],[2]]`
+    List(&'static Type),
+    /// `HashMap<K, V>` or `BTreeMap<K, V>` of key and value types. Encoded as
+    /// JSON object of values sorted by key: `{"lang":"en","theme":"dark"}`
+    Map {
+        key:   &'static Type,
+        value: &'static Type,
+    },
+    /// Field of type which derives `Reflected`. Encoded as JSON object of its
+    /// field values: `{"city":"Vilnius","zip":1000}`
+    Struct(&'static StructType),
+    #[cfg(feature = "uuid")]
+    Uuid,
+    /// `serde_json::Value`. Encoded as compact JSON text
+    #[cfg(feature = "json")]
+    Json,
     Optional(OptionalType),
 }
 
 impl Type {
+    /// Optional version of the type. Optional types are returned as is
     pub const fn to_optional(self) -> Self {
         Self::Optional(OptionalType::from_type(&self))
     }
 
+    /// Type of field of type `self` with `#[reflected(type = "...")]` of
+    /// `tp`. Only text values can be reflected as other types. Panics during
+    /// const evaluation for other values
+    #[doc(hidden)]
+    pub const fn reflected_as(self, tp: Self) -> Self {
+        let (value, optional) = match self {
+            Self::Optional(value) => (value.to_non_optional(), true),
+            value => (value, false),
+        };
+
+        let compatible = matches!(
+            (value, tp),
+            (Self::Text, _)
+                | (Self::Float, Self::Float)
+                | (Self::Integer, Self::Integer)
+                | (Self::Date, Self::Date)
+                | (Self::Decimal, Self::Decimal)
+                | (Self::Bool, Self::Bool)
+                | (Self::Enum, Self::Enum)
+                | (Self::Duration, Self::Duration)
+                | (Self::DateTime, Self::DateTime)
+                | (Self::NaiveDate, Self::NaiveDate)
+                | (Self::NaiveTime, Self::NaiveTime)
+                | (Self::Bytes, Self::Bytes)
+        );
+
+        #[cfg(feature = "uuid")]
+        let compatible = compatible || matches!((value, tp), (Self::Uuid, Self::Uuid));
+
+        #[cfg(feature = "json")]
+        let compatible = compatible || matches!((value, tp), (Self::Json, Self::Json));
+
+        assert!(
+            compatible,
+            "#[reflected(type = \"...\")] contradicts ReflectedValue::TYPE of the field. Only text values \
+             can be reflected as other types"
+        );
+
+        if optional { tp.to_optional() } else { tp }
+    }
+
     pub fn is_type(&self, tp: Self) -> bool {
         if self == &tp {
             return true;
@@ -104,6 +209,70 @@ impl Type {
         self.is_type(Self::Duration)
     }
 
+    pub fn is_date_time(&self) -> bool {
+        self.is_type(Self::DateTime)
+    }
+
+    pub fn is_naive_date(&self) -> bool {
+        self.is_type(Self::NaiveDate)
+    }
+
+    pub fn is_naive_time(&self) -> bool {
+        self.is_type(Self::NaiveTime)
+    }
+
+    pub fn is_bytes(&self) -> bool {
+        self.is_type(Self::Bytes)
+    }
+
+    pub fn is_list(&self) -> bool {
+        self.list_element().is_some()
+    }
+
+    /// Element type of `List` or optional `List`
+    pub fn list_element(&self) -> Option<Type> {
+        match self {
+            Self::List(element) | Self::Optional(OptionalType::List(element)) => Some(**element),
+            _ => None,
+        }
+    }
+
+    pub fn is_map(&self) -> bool {
+        self.map_types().is_some()
+    }
+
+    /// Key and value types of `Map` or optional `Map`
+    pub fn map_types(&self) -> Option<(Type, Type)> {
+        match self {
+            Self::Map { key, value } | Self::Optional(OptionalType::Map { key, value }) => {
+                Some((**key, **value))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_struct(&self) -> bool {
+        self.struct_type().is_some()
+    }
+
+    /// Schema of `Struct` or optional `Struct`
+    pub fn struct_type(&self) -> Option<&'static StructType> {
+        match self {
+            Self::Struct(tp) | Self::Optional(OptionalType::Struct(tp)) => Some(tp),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    pub fn is_uuid(&self) -> bool {
+        self.is_type(Self::Uuid)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn is_json(&self) -> bool {
+        self.is_type(Self::Json)
+    }
+
     pub fn is_optional(&self) -> bool {
         matches!(self, Self::Optional(_))
     }
@@ -128,5 +297,20 @@ mod test {
     fn test() {
         assert!(Type::Float.is_float());
         assert!(Type::Float.to_optional().is_float());
+
+        assert!(Type::Enum.to_optional().is_enum());
+        assert!(Type::Enum.to_optional().is_optional());
+        assert_eq!(Type::Enum.to_optional().to_optional(), Type::Enum.to_optional());
+    }
+
+    #[test]
+    fn reflected_as() {
+        assert_eq!(Type::Text.reflected_as(Type::Integer), Type::Integer);
+        assert_eq!(
+            Type::Text.to_optional().reflected_as(Type::Date),
+            Type::Date.to_optional()
+        );
+        assert_eq!(Type::Decimal.reflected_as(Type::Decimal), Type::Decimal);
+        assert!(std::panic::catch_unwind(|| Type::Integer.reflected_as(Type::Date)).is_err());
     }
 }
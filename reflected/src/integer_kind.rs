@@ -0,0 +1,90 @@
+/// Width and signedness of a `Type::Integer` field
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IntegerKind {
+    pub bits:     u32,
+    pub signed:   bool,
+    /// `NonZero*` types
+    pub non_zero: bool,
+}
+
+impl IntegerKind {
+    pub const fn new(bits: u32, signed: bool) -> Self {
+        Self {
+            bits,
+            signed,
+            non_zero: false,
+        }
+    }
+
+    pub const fn non_zero(self) -> Self {
+        Self {
+            non_zero: true,
+            ..self
+        }
+    }
+
+    /// Smallest representable value
+    pub const fn min(&self) -> i128 {
+        if !self.signed {
+            return if self.non_zero { 1 } else { 0 };
+        }
+
+        if self.bits == 128 {
+            i128::MIN
+        } else {
+            -(1 << (self.bits - 1))
+        }
+    }
+
+    /// Largest representable value
+    pub const fn max(&self) -> u128 {
+        let bits = if self.signed { self.bits - 1 } else { self.bits };
+
+        if bits == 128 { u128::MAX } else { (1 << bits) - 1 }
+    }
+
+    /// `true` if `value` fits into this integer type
+    pub fn contains(&self, value: i128) -> bool {
+        if self.non_zero && value == 0 {
+            return false;
+        }
+
+        value >= self.min() && (value < 0 || value.unsigned_abs() <= self.max())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::{NonZeroI8, NonZeroU32};
+
+    use crate::{IntegerKind, ReflectedValue};
+
+    #[test]
+    fn bounds() {
+        let kind = u8::INTEGER_KIND.unwrap();
+        assert_eq!(kind, IntegerKind::new(8, false));
+        assert_eq!((kind.min(), kind.max()), (0, 255));
+
+        let kind = i64::INTEGER_KIND.unwrap();
+        assert_eq!((kind.min(), kind.max()), (i128::from(i64::MIN), i64::MAX as u128));
+
+        let kind = i128::INTEGER_KIND.unwrap();
+        assert_eq!((kind.min(), kind.max()), (i128::MIN, i128::MAX as u128));
+        assert_eq!(u128::INTEGER_KIND.unwrap().max(), u128::MAX);
+
+        let kind = NonZeroU32::INTEGER_KIND.unwrap();
+        assert_eq!(kind, IntegerKind::new(32, false).non_zero());
+        assert_eq!((kind.min(), kind.max()), (1, u128::from(u32::MAX)));
+
+        assert_eq!(Option::<NonZeroI8>::INTEGER_KIND, NonZeroI8::INTEGER_KIND);
+        assert_eq!(String::INTEGER_KIND, None);
+
+        let kind = i8::INTEGER_KIND.unwrap();
+        assert!(kind.contains(-128));
+        assert!(kind.contains(127));
+        assert!(!kind.contains(128));
+        assert!(!kind.contains(-129));
+        assert!(!NonZeroI8::INTEGER_KIND.unwrap().contains(0));
+        assert!(!u32::INTEGER_KIND.unwrap().contains(-1));
+    }
+}
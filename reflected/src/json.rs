@@ -0,0 +1,463 @@
+//! Minimal JSON used for string encoding of collection and struct fields
+
+use std::fmt::Write;
+
+use crate::Type;
+
+/// Element of encoded JSON array
+#[derive(Debug, PartialEq)]
+pub(crate) enum JsonItem {
+    Null,
+    /// Number, bool or nested array/object as written
+    Raw(String),
+    /// Unescaped string
+    Str(String),
+}
+
+pub(crate) fn encode_array(items: impl IntoIterator<Item = JsonItem>) -> String {
+    let items: Vec<String> = items.into_iter().map(|item| encode_item(&item)).collect();
+    format!("[{}]", items.join(","))
+}
+
+/// JSON item of reflected value of type `tp`
+pub(crate) fn typed_item(tp: Type, value: String) -> JsonItem {
+    if tp.is_optional() && value == "NULL" {
+        return JsonItem::Null;
+    }
+
+    let tp = match tp {
+        Type::Optional(tp) => tp.to_non_optional(),
+        tp => tp,
+    };
+
+    let raw = matches!(
+        tp,
+        Type::Integer
+            | Type::Float
+            | Type::Decimal
+            | Type::Bool
+            | Type::List(_)
+            | Type::Map { .. }
+            | Type::Struct(_)
+    );
+
+    #[cfg(feature = "json")]
+    let raw = raw || tp == Type::Json;
+
+    match (tp, value.as_str()) {
+        (Type::Bool, "1") => return JsonItem::Raw("true".to_string()),
+        (Type::Bool, "0") => return JsonItem::Raw("false".to_string()),
+        // JSON has no NaN and infinity literals
+        (Type::Float, value) if value.parse::<f64>().is_ok_and(|value| !value.is_finite()) => {
+            return JsonItem::Str(value.to_string());
+        }
+        _ => (),
+    }
+
+    if raw {
+        JsonItem::Raw(value)
+    } else {
+        JsonItem::Str(value)
+    }
+}
+
+impl JsonItem {
+    /// Reflected value of the item
+    pub(crate) fn into_value(self) -> Option<String> {
+        match self {
+            Self::Null => None,
+            Self::Raw(value) | Self::Str(value) => Some(value),
+        }
+    }
+
+    /// Reflected value of the item of type `tp`. Reverse of `typed_item`
+    pub(crate) fn into_typed_value(self, tp: Type) -> Option<String> {
+        let value = self.into_value()?;
+
+        if !tp.is_bool() {
+            return Some(value);
+        }
+
+        Some(match value.as_str() {
+            "true" => "1".to_string(),
+            "false" => "0".to_string(),
+            _ => value,
+        })
+    }
+}
+
+pub(crate) fn encode_object(items: impl IntoIterator<Item = (String, JsonItem)>) -> String {
+    let items: Vec<String> = items
+        .into_iter()
+        .map(|(key, item)| format!("{}:{}", encode_string(&key), encode_item(&item)))
+        .collect();
+    format!("{{{}}}", items.join(","))
+}
+
+pub(crate) fn encode_item(item: &JsonItem) -> String {
+    match item {
+        JsonItem::Null => "null".to_string(),
+        JsonItem::Raw(raw) => raw.clone(),
+        JsonItem::Str(string) => encode_string(string),
+    }
+}
+
+pub(crate) fn encode_string(string: &str) -> String {
+    let mut result = String::with_capacity(string.len() + 2);
+
+    result.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if u32::from(ch) < 0x20 => write!(result, "\\u{:04x}", u32::from(ch)).unwrap(),
+            ch => result.push(ch),
+        }
+    }
+
+    result.push('"');
+
+    result
+}
+
+pub(crate) fn decode_array(value: &str) -> Result<Vec<JsonItem>, String> {
+    let mut parser = Parser::new(value, "array");
+    parser.sequence('[', ']', Parser::item)
+}
+
+pub(crate) fn decode_object(value: &str) -> Result<Vec<(String, JsonItem)>, String> {
+    let mut parser = Parser::new(value, "object");
+    parser.sequence('{', '}', |parser| {
+        let key = parser.string()?;
+        parser.skip_whitespace();
+        parser.expect(':')?;
+        parser.skip_whitespace();
+        Ok((key, parser.item()?))
+    })
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    value: &'a str,
+    kind:  &'static str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(value: &'a str, kind: &'static str) -> Self {
+        Self {
+            chars: value.char_indices().peekable(),
+            value,
+            kind,
+        }
+    }
+
+    /// Whole input of `open`, comma separated elements and `close`
+    fn sequence<T>(
+        &mut self,
+        open: char,
+        close: char,
+        mut element: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        self.skip_whitespace();
+        self.expect(open)?;
+        self.skip_whitespace();
+
+        let mut elements = vec![];
+
+        if self.peek() == Some(close) {
+            self.next();
+        } else {
+            loop {
+                self.skip_whitespace();
+                elements.push(element(self)?);
+                self.skip_whitespace();
+
+                match self.next() {
+                    Some(',') => (),
+                    Some(ch) if ch == close => break,
+                    _ => return Err(self.error(&format!("Expected `,` or `{close}`"))),
+                }
+            }
+        }
+
+        self.skip_whitespace();
+
+        if self.peek().is_some() {
+            return Err(self.error("Unexpected trailing characters"));
+        }
+
+        Ok(elements)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, ch)| *ch)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        self.chars.next().map(|(_, ch)| ch)
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.value.len(), |(index, _)| *index)
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("Invalid JSON {}: {}. {message}", self.kind, self.value)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(ch) if ch == expected => Ok(()),
+            _ => Err(self.error(&format!("Expected `{expected}`"))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn item(&mut self) -> Result<JsonItem, String> {
+        match self.peek() {
+            Some('"') => self.string().map(JsonItem::Str),
+            Some('[' | '{') => self.nested().map(JsonItem::Raw),
+            Some(_) => {
+                let start = self.position();
+                while self
+                    .peek()
+                    .is_some_and(|ch| !matches!(ch, ',' | ']' | '}') && !ch.is_whitespace())
+                {
+                    self.next();
+                }
+                let raw = &self.value[start..self.position()];
+
+                match raw {
+                    "" => Err(self.error("Expected value")),
+                    "null" => Ok(JsonItem::Null),
+                    raw => Ok(JsonItem::Raw(raw.to_string())),
+                }
+            }
+            None => Err(self.error("Unexpected end")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut result = String::new();
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(result),
+                Some('\\') => match self.next() {
+                    Some('"') => result.push('"'),
+                    Some('\\') => result.push('\\'),
+                    Some('/') => result.push('/'),
+                    Some('n') => result.push('\n'),
+                    Some('r') => result.push('\r'),
+                    Some('t') => result.push('\t'),
+                    Some('b') => result.push('\u{8}'),
+                    Some('f') => result.push('\u{c}'),
+                    Some('u') => result.push(self.unicode_escape()?),
+                    _ => return Err(self.error("Invalid escape")),
+                },
+                Some(ch) => result.push(ch),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    /// Character of `\uXXXX` escape after `\u`. Characters outside of the
+    /// Basic Multilingual Plane are escaped as surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.code_unit()?;
+
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high)
+                .ok_or_else(|| self.error(&format!("Invalid unicode escape: {high:04x}")));
+        }
+
+        if self.next() != Some('\\') || self.next() != Some('u') {
+            return Err(self.error(&format!("Unpaired surrogate: {high:04x}")));
+        }
+
+        let low = self.code_unit()?;
+
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error(&format!("Invalid low surrogate: {low:04x}")));
+        }
+
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or_else(|| self.error(&format!("Invalid surrogate pair: {high:04x} {low:04x}")))
+    }
+
+    /// 4 hex digits of `\uXXXX` escape
+    fn code_unit(&mut self) -> Result<u32, String> {
+        let code: String = (0..4).filter_map(|_| self.next()).collect();
+        u32::from_str_radix(&code, 16).map_err(|_| self.error(&format!("Invalid unicode escape: {code}")))
+    }
+
+    /// Nested array or object kept as is
+    fn nested(&mut self) -> Result<String, String> {
+        let start = self.position();
+        let mut closing = vec![];
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.string()?;
+                    continue;
+                }
+                Some('[') => closing.push(']'),
+                Some('{') => closing.push('}'),
+                Some(ch @ (']' | '}')) => {
+                    if closing.pop() != Some(ch) {
+                        return Err(self.error(&format!("Unexpected `{ch}`")));
+                    }
+                }
+                Some(_) => (),
+                None => return Err(self.error("Unexpected end")),
+            }
+
+            self.next();
+
+            if closing.is_empty() {
+                return Ok(self.value[start..self.position()].to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        Type,
+        json::{JsonItem, decode_array, decode_object, encode_array, encode_object, typed_item},
+    };
+
+    #[test]
+    fn array() {
+        let items = vec![
+            JsonItem::Raw("1".to_string()),
+            JsonItem::Null,
+            JsonItem::Str("a \"b\",\n[c]".to_string()),
+            JsonItem::Raw("[1,[2]]".to_string()),
+        ];
+
+        let encoded = encode_array(items);
+
+        assert_eq!(encoded, r#"[1,null,"a \"b\",\n[c]",[1,[2]]]"#);
+
+        assert_eq!(
+            decode_array(&encoded).unwrap(),
+            vec![
+                JsonItem::Raw("1".to_string()),
+                JsonItem::Null,
+                JsonItem::Str("a \"b\",\n[c]".to_string()),
+                JsonItem::Raw("[1,[2]]".to_string()),
+            ]
+        );
+
+        assert_eq!(decode_array(" [ ] ").unwrap(), vec![]);
+        assert_eq!(
+            decode_array(r#"[ "A" , true ]"#).unwrap(),
+            vec![JsonItem::Str("A".to_string()), JsonItem::Raw("true".to_string())]
+        );
+
+        assert!(decode_array("[1,").is_err());
+        assert!(decode_array("[1,]").is_err());
+        assert!(decode_array("1").is_err());
+        assert!(decode_array("[1] 2").is_err());
+        assert!(decode_array(r#"["a]"#).is_err());
+
+        assert_eq!(
+            decode_array(r#"["\ud83d\ude00 \u00e9"]"#).unwrap(),
+            vec![JsonItem::Str("\u{1f600} \u{e9}".to_string())]
+        );
+        assert!(decode_array(r#"["\ud83d"]"#).is_err());
+        assert!(decode_array(r#"["\ud83d\u0041"]"#).is_err());
+        assert!(decode_array(r#"["\ude00"]"#).is_err());
+
+        assert_eq!(
+            decode_array(r#"[[1,{"a":[2]}]]"#).unwrap(),
+            vec![JsonItem::Raw(r#"[1,{"a":[2]}]"#.to_string())]
+        );
+        assert!(decode_array("[[1}]").is_err());
+        assert!(decode_array(r#"[{"a":1]}]"#).is_err());
+    }
+
+    #[test]
+    fn object() {
+        let items = vec![
+            ("name".to_string(), JsonItem::Str("Alex".to_string())),
+            (
+                "address".to_string(),
+                JsonItem::Raw(r#"{"city":"Vilnius"}"#.to_string()),
+            ),
+            ("age".to_string(), JsonItem::Null),
+        ];
+
+        let encoded = encode_object(items);
+
+        assert_eq!(
+            encoded,
+            r#"{"name":"Alex","address":{"city":"Vilnius"},"age":null}"#
+        );
+
+        assert_eq!(
+            decode_object(&encoded).unwrap(),
+            vec![
+                ("name".to_string(), JsonItem::Str("Alex".to_string())),
+                (
+                    "address".to_string(),
+                    JsonItem::Raw(r#"{"city":"Vilnius"}"#.to_string())
+                ),
+                ("age".to_string(), JsonItem::Null),
+            ]
+        );
+
+        assert_eq!(decode_object(" { } ").unwrap(), vec![]);
+        assert!(decode_object(r#"{"a"}"#).is_err());
+        assert!(decode_object(r#"{a: 1}"#).is_err());
+        assert!(decode_object("[]").is_err());
+    }
+
+    #[test]
+    fn typed() {
+        assert_eq!(
+            typed_item(Type::Bool, "1".to_string()),
+            JsonItem::Raw("true".to_string())
+        );
+        assert_eq!(
+            typed_item(Type::Bool.to_optional(), "0".to_string()),
+            JsonItem::Raw("false".to_string())
+        );
+        assert_eq!(
+            typed_item(Type::Float, "1.5".to_string()),
+            JsonItem::Raw("1.5".to_string())
+        );
+        assert_eq!(
+            typed_item(Type::Float, "NaN".to_string()),
+            JsonItem::Str("NaN".to_string())
+        );
+        assert_eq!(
+            typed_item(Type::Float, "-inf".to_string()),
+            JsonItem::Str("-inf".to_string())
+        );
+
+        assert_eq!(
+            JsonItem::Raw("false".to_string()).into_typed_value(Type::Bool),
+            Some("0".to_string())
+        );
+        assert_eq!(
+            JsonItem::Raw("true".to_string()).into_typed_value(Type::Text),
+            Some("true".to_string())
+        );
+        assert_eq!(JsonItem::Null.into_typed_value(Type::Bool), None);
+    }
+}
@@ -1,18 +1,49 @@
 mod field;
 mod field_type;
+mod integer_kind;
+mod json;
+mod list;
+mod map;
+mod map_policy;
+mod path;
 #[cfg(feature = "random")]
 mod random;
+mod reflect_error;
 mod reflected;
+mod reflected_bytes;
+mod reflected_date;
+mod reflected_enum;
 mod reflected_eq;
+mod reflected_value;
+#[cfg(feature = "sqlx_bind")]
+mod sqlx_bind;
+mod struct_type;
 mod to_reflected_string;
 mod to_reflected_val;
+mod typed_field;
+mod value;
 
 pub use field::Field;
 pub use field_type::{OptionalType, Type};
+pub use integer_kind::IntegerKind;
+pub use map_policy::{MapPolicy, MissingKeys, UnknownKeys};
 #[cfg(feature = "random")]
 pub use random::RandomReflected;
+pub use reflect_error::{ReflectError, ReflectErrorKind};
 pub use reflected::Reflected;
+pub use reflected_bytes::{BytesEncoding, ReflectedBytes};
+pub use reflected_date::ReflectedDate;
+pub use reflected_enum::ReflectedEnum;
 pub use reflected_eq::ReflectedEq;
 pub use reflected_proc::Reflected;
+pub use reflected_value::ReflectedValue;
+#[cfg(feature = "sqlx_bind")]
+pub use sqlx_bind::SqlxQuery;
+#[cfg(feature = "sqlx_bind")]
+#[doc(hidden)]
+pub use sqlx_bind::{BindNative, BindReflected, BindValue};
+pub use struct_type::StructType;
 pub use to_reflected_string::ToReflectedString;
 pub use to_reflected_val::ToReflectedVal;
+pub use typed_field::TypedField;
+pub use value::Value;
@@ -0,0 +1,171 @@
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, Hash},
+};
+
+#[cfg(feature = "sqlx_bind")]
+use crate::SqlxQuery;
+use crate::{
+    ReflectedValue, Type, Value,
+    json::{JsonItem, decode_array, encode_array, encode_item, typed_item},
+    reflected_value::non_null,
+};
+
+/// JSON array of element values
+pub(crate) fn encode<'a, T: ReflectedValue + 'a>(items: impl IntoIterator<Item = &'a T>) -> String {
+    encode_array(items.into_iter().map(to_json_item))
+}
+
+pub(crate) fn decode<T: ReflectedValue>(value: &str) -> Result<Vec<T>, String> {
+    decode_array(value)?
+        .into_iter()
+        .map(|item| T::from_reflected_value(item.into_typed_value(T::TYPE).as_deref()))
+        .collect()
+}
+
+/// `Value::List` of element values
+pub(crate) fn to_value<'a, T: ReflectedValue + 'a>(items: impl IntoIterator<Item = &'a T>) -> Value {
+    Value::List(items.into_iter().map(T::to_value).collect())
+}
+
+pub(crate) fn from_value<T: ReflectedValue>(value: Value) -> Result<Vec<T>, String> {
+    let Value::List(items) = value else {
+        return Err(format!("Expected List value, got: {value:?}"));
+    };
+    items.into_iter().map(T::from_value).collect()
+}
+
+fn to_json_item<T: ReflectedValue>(item: &T) -> JsonItem {
+    typed_item(T::TYPE, item.to_reflected_value())
+}
+
+impl<T: ReflectedValue> ReflectedValue for Vec<T> {
+    const TYPE: Type = T::LIST_TYPE;
+
+    fn to_reflected_value(&self) -> String {
+        T::encode_list(self)
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        T::decode_list(non_null(value)?)
+    }
+
+    fn to_value(&self) -> Value {
+        T::list_to_value(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        T::list_from_value(value)
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        T::bind_sqlx_list(value.map(|items| items.iter().collect()), query)
+    }
+}
+
+impl<T: ReflectedValue, const N: usize> ReflectedValue for [T; N] {
+    const TYPE: Type = T::LIST_TYPE;
+
+    fn to_reflected_value(&self) -> String {
+        T::encode_list(self)
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        T::decode_list(non_null(value)?)?.try_into().map_err(|items: Vec<T>| {
+            format!(
+                "Invalid length of: {value:?}. Expected: {N}, got: {}",
+                items.len()
+            )
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        T::list_to_value(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        T::list_from_value(value)?
+            .try_into()
+            .map_err(|items: Vec<T>| format!("Invalid list length. Expected: {N}, got: {}", items.len()))
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        T::bind_sqlx_list(value.map(|items| items.iter().collect()), query)
+    }
+}
+
+/// Items of `set` sorted by encoding to keep `get_value` of equal sets equal
+fn sorted<T: ReflectedValue, S>(set: &HashSet<T, S>) -> Vec<&T> {
+    let mut items: Vec<_> = set.iter().map(|item| (encode_item(&to_json_item(item)), item)).collect();
+    items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+impl<T: ReflectedValue + Eq + Hash, S: BuildHasher + Default> ReflectedValue for HashSet<T, S> {
+    const TYPE: Type = Type::List(&T::TYPE);
+
+    fn to_reflected_value(&self) -> String {
+        encode(sorted(self))
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        decode(non_null(value)?).map(Vec::into_iter).map(Iterator::collect)
+    }
+
+    fn to_value(&self) -> Value {
+        to_value(sorted(self))
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        from_value(value).map(Vec::into_iter).map(Iterator::collect)
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        T::bind_sqlx_list(value.map(sorted), query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{ReflectedValue, Type};
+
+    #[test]
+    fn list() {
+        assert_eq!(<Vec<i64>>::TYPE, Type::List(&Type::Integer));
+        assert_eq!(<Vec<Vec<String>>>::TYPE, Type::List(&Type::List(&Type::Text)));
+        assert_eq!(<[u8; 4]>::TYPE, Type::Bytes);
+        assert_eq!(
+            <Vec<Option<bool>>>::TYPE.list_element(),
+            Some(Type::Bool.to_optional())
+        );
+
+        assert_eq!(vec![1, 2, 3].to_reflected_value(), "[1,2,3]");
+        assert_eq!(vec![Some(1.0), None].to_reflected_value(), "[1.0,null]");
+        assert_eq!(
+            vec![vec!["a".to_string()], vec![]].to_reflected_value(),
+            r#"[["a"],[]]"#
+        );
+
+        assert_eq!(Vec::<u16>::from_reflected_value(Some("[1, 2]")), Ok(vec![1, 2]));
+        assert_eq!(
+            Vec::<Option<String>>::from_reflected_value(Some(r#"["a", null]"#)),
+            Ok(vec![Some("a".to_string()), None])
+        );
+        assert_eq!(<[i32; 2]>::from_reflected_value(Some("[5,6]")), Ok([5, 6]));
+        assert!(<[i32; 2]>::from_reflected_value(Some("[5]")).is_err());
+        assert!(Vec::<i32>::from_reflected_value(Some(r#"["a"]"#)).is_err());
+        assert_eq!(
+            Vec::<String>::from_reflected_value(Some(r#"["\ud83d\ude00"]"#)),
+            Ok(vec!["\u{1f600}".to_string()])
+        );
+
+        let set: HashSet<String> = ["b", "a"].into_iter().map(ToString::to_string).collect();
+        assert_eq!(set.to_reflected_value(), r#"["a","b"]"#);
+        assert_eq!(HashSet::from_reflected_value(Some(r#"["b","a"]"#)), Ok(set));
+    }
+}
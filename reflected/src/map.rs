@@ -0,0 +1,148 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    ReflectedValue, Type, Value,
+    json::{decode_object, encode_object, typed_item},
+    reflected_value::non_null,
+};
+
+/// JSON object of entry values sorted by key
+fn encode<'a, K: ReflectedValue + 'a, V: ReflectedValue + 'a>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+) -> String {
+    let mut entries: Vec<_> = entries
+        .into_iter()
+        .map(|(key, value)| {
+            (
+                key.to_reflected_value(),
+                typed_item(V::TYPE, value.to_reflected_value()),
+            )
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    encode_object(entries)
+}
+
+fn decode<K: ReflectedValue, V: ReflectedValue>(value: &str) -> Result<Vec<(K, V)>, String> {
+    decode_object(value)?
+        .into_iter()
+        .map(|(key, item)| {
+            Ok((
+                K::from_reflected_value(Some(&key))?,
+                V::from_reflected_value(item.into_typed_value(V::TYPE).as_deref())?,
+            ))
+        })
+        .collect()
+}
+
+/// `Value::Map` of entry values by reflected keys
+fn to_value<'a, K: ReflectedValue + 'a, V: ReflectedValue + 'a>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+) -> Value {
+    Value::Map(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_reflected_value(), value.to_value()))
+            .collect(),
+    )
+}
+
+fn from_value<K: ReflectedValue, V: ReflectedValue>(value: Value) -> Result<Vec<(K, V)>, String> {
+    let Value::Map(entries) = value else {
+        return Err(format!("Expected Map value, got: {value:?}"));
+    };
+    entries
+        .into_iter()
+        .map(|(key, value)| Ok((K::from_reflected_value(Some(&key))?, V::from_value(value)?)))
+        .collect()
+}
+
+impl<K: ReflectedValue + Eq + Hash, V: ReflectedValue, S: BuildHasher + Default> ReflectedValue
+    for HashMap<K, V, S>
+{
+    const TYPE: Type = Type::Map {
+        key:   &K::TYPE,
+        value: &V::TYPE,
+    };
+
+    fn to_reflected_value(&self) -> String {
+        encode(self)
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        decode(non_null(value)?).map(Vec::into_iter).map(Iterator::collect)
+    }
+
+    fn to_value(&self) -> Value {
+        to_value(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        from_value(value).map(Vec::into_iter).map(Iterator::collect)
+    }
+}
+
+impl<K: ReflectedValue + Ord, V: ReflectedValue> ReflectedValue for BTreeMap<K, V> {
+    const TYPE: Type = Type::Map {
+        key:   &K::TYPE,
+        value: &V::TYPE,
+    };
+
+    fn to_reflected_value(&self) -> String {
+        encode(self)
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        decode(non_null(value)?).map(Vec::into_iter).map(Iterator::collect)
+    }
+
+    fn to_value(&self) -> Value {
+        to_value(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        from_value(value).map(Vec::into_iter).map(Iterator::collect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::{ReflectedValue, Type};
+
+    #[test]
+    fn map() {
+        assert_eq!(
+            <HashMap<String, Option<i64>>>::TYPE.map_types(),
+            Some((Type::Text, Type::Integer.to_optional()))
+        );
+        assert_eq!(
+            <BTreeMap<String, Vec<bool>>>::TYPE.map_types(),
+            Some((Type::Text, Type::List(&Type::Bool)))
+        );
+
+        let map: HashMap<String, Option<i64>> =
+            [("b".to_string(), Some(2)), ("a".to_string(), None)].into_iter().collect();
+
+        assert_eq!(map.to_reflected_value(), r#"{"a":null,"b":2}"#);
+        assert_eq!(
+            HashMap::from_reflected_value(Some(r#"{"b": 2, "a": null}"#)),
+            Ok(map)
+        );
+
+        let map: BTreeMap<String, String> = [("theme".to_string(), "dark".to_string())].into_iter().collect();
+
+        assert_eq!(map.to_reflected_value(), r#"{"theme":"dark"}"#);
+        assert_eq!(
+            BTreeMap::from_reflected_value(Some(r#"{"theme":"dark"}"#)),
+            Ok(map)
+        );
+
+        assert!(BTreeMap::<String, i64>::from_reflected_value(Some(r#"{"a":"b"}"#)).is_err());
+        assert!(BTreeMap::<String, i64>::from_reflected_value(None).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+/// Handling of map keys in `Reflected::from_map_with`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct MapPolicy {
+    pub unknown: UnknownKeys,
+    pub missing: MissingKeys,
+}
+
+/// Keys which are not field names
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum UnknownKeys {
+    #[default]
+    Error,
+    Ignore,
+}
+
+/// Fields without a key
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum MissingKeys {
+    /// Keep `Default` value of the field
+    #[default]
+    Default,
+    Error,
+}
+
+impl MapPolicy {
+    /// Every field must be present and every key must be a field
+    pub const STRICT: Self = Self {
+        unknown: UnknownKeys::Error,
+        missing: MissingKeys::Error,
+    };
+
+    /// Unknown keys are skipped and missing fields keep default values
+    pub const LENIENT: Self = Self {
+        unknown: UnknownKeys::Ignore,
+        missing: MissingKeys::Default,
+    };
+}
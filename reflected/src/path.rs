@@ -0,0 +1,112 @@
+//! Dotted path access to fields of nested `Type::Struct` fields and entries
+//! of `Type::Map` fields
+
+use crate::{
+    Field, ReflectError, ReflectErrorKind, Reflected, Type,
+    json::{JsonItem, decode_object, encode_object, typed_item},
+};
+
+pub(crate) fn walk_fields<T>(fields: &[Field<T>], prefix: &str, f: &mut impl FnMut(&str, &Field<()>)) {
+    for field in fields {
+        let path = format!("{prefix}{}", field.name);
+
+        f(&path, &field.erased());
+
+        if let Some(tp) = field.struct_type() {
+            walk_fields(tp.fields(), &format!("{path}."), f);
+        }
+    }
+}
+
+/// Value at `path` inside encoded struct or map `value` of type `tp`
+pub(crate) fn nested_value(tp: Type, value: &str, path: &str) -> Result<String, ReflectErrorKind> {
+    if value == "NULL" {
+        return Ok(value.to_string());
+    }
+
+    let (name, rest) = split(path);
+    let child = child_type(tp, name)?;
+
+    let value = decode_object(value)
+        .map_err(ReflectErrorKind::Parse)?
+        .into_iter()
+        .find(|(key, _)| key == name)
+        .map_or(JsonItem::Null, |(_, item)| item)
+        .into_typed_value(child)
+        .unwrap_or_else(|| "NULL".to_string());
+
+    match rest {
+        Some(rest) => nested_value(child, &value, rest),
+        None => Ok(value),
+    }
+}
+
+/// Encoded struct or map `current` of type `tp` with `value` at `path`
+pub(crate) fn set_nested_value(
+    tp: Type,
+    current: &str,
+    path: &str,
+    value: Option<&str>,
+) -> Result<String, ReflectErrorKind> {
+    let (name, rest) = split(path);
+    let child = child_type(tp, name)?;
+
+    if current == "NULL" {
+        return Err(ReflectErrorKind::Parse(format!(
+            "Failed to set {path}. Parent struct is NULL"
+        )));
+    }
+
+    let mut items = decode_object(current).map_err(ReflectErrorKind::Parse)?;
+
+    let value = match rest {
+        Some(rest) => {
+            let current = nested_value(tp, current, name)?;
+            Some(set_nested_value(child, &current, rest, value)?)
+        }
+        None => value.map(ToString::to_string),
+    };
+
+    let item = value.map_or(JsonItem::Null, |value| typed_item(child, value));
+
+    // `NULL` removes map entry
+    if tp.is_map() && item == JsonItem::Null {
+        items.retain(|(key, _)| key != name);
+        return Ok(encode_object(items));
+    }
+
+    match items.iter_mut().find(|(key, _)| key == name) {
+        Some((_, existing)) => *existing = item,
+        None => items.push((name.to_string(), item)),
+    }
+
+    if tp.is_map() {
+        items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+
+    Ok(encode_object(items))
+}
+
+pub(crate) fn split(path: &str) -> (&str, Option<&str>) {
+    match path.split_once('.') {
+        Some((name, rest)) => (name, Some(rest)),
+        None => (path, None),
+    }
+}
+
+/// Type of struct field or map entry `name`
+fn child_type(tp: Type, name: &str) -> Result<Type, ReflectErrorKind> {
+    if let Some((_, value)) = tp.map_types() {
+        return Ok(value);
+    }
+
+    tp.struct_type()
+        .and_then(|tp| tp.field(name))
+        .map(|field| field.tp)
+        .ok_or(ReflectErrorKind::UnknownField)
+}
+
+pub(crate) fn field_by_name<T: Reflected>(name: &str) -> Result<Field<T>, ReflectError> {
+    T::try_field_by_name(name)
+        .ok_or_else(|| ReflectError::new(T::type_name(), name, None, ReflectErrorKind::UnknownField))
+}
@@ -1,52 +1,105 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use fake::{Fake, Faker, faker::internet::en::FreeEmail};
 use rust_decimal::Decimal;
 
-use crate::{Field, Reflected, Type};
+use crate::{Field, IntegerKind, Reflected, Type, Value};
 
 pub trait RandomReflected {
     fn random() -> Self;
 }
 
+/// Values are set with typed `Reflected::set`, so fields with `format` and
+/// `encoding` attributes get valid values
 impl<T: Reflected> RandomReflected for T {
     fn random() -> Self {
         let mut new = Self::default();
 
-        for field in Self::fields() {
-            if matches!(field.tp, Type::DateTime | Type::Enum) {
+        for field in Self::random_fields() {
+            if !can_be_random(field) {
                 continue;
             }
 
-            new.set_value(*field, random_val(field).as_deref());
+            new.set(*field, random_value(field)).unwrap_or_else(|err| panic!("{err}"));
         }
 
         new
     }
 }
 
-fn random_val<T>(field: &Field<T>) -> Option<String> {
+/// Encoding and length of bytes, lists and maps is not known here
+fn can_be_random<T>(field: &Field<T>) -> bool {
+    field.random && !field.is_enum() && !field.is_bytes() && !field.is_list() && !field.is_map()
+}
+
+fn random_value<T>(field: &Field<T>) -> Value {
     match field.tp {
         Type::Text => {
             if field.name.contains("email") {
-                FreeEmail().fake::<String>().into()
+                Value::Text(FreeEmail().fake())
             } else {
-                16.fake::<String>().into()
+                Value::Text(16.fake())
             }
         }
-        Type::Integer | Type::Float => (0..1_000).fake::<i64>().to_string().into(),
-        Type::Date => Utc::now().naive_utc().to_string().into(),
-        Type::Decimal => Decimal::new(i64::from((u32::MIN..u32::MAX).fake::<u32>()), (1..6).fake())
-            .to_string()
-            .into(),
-        Type::Bool => (0..2).fake::<i32>().to_string().into(),
+        Type::Integer => Value::Int(random_integer(field.integer_kind)),
+        Type::Float => Value::Float((0..1_000).fake::<i32>().into()),
+        Type::Date => Value::Date(Utc::now().naive_utc()),
+        Type::DateTime => Value::DateTime(Utc::now().fixed_offset()),
+        Type::NaiveDate => Value::NaiveDate(Utc::now().date_naive()),
+        Type::NaiveTime => Value::NaiveTime(Utc::now().time()),
+        Type::Decimal => Value::Decimal(Decimal::new(
+            i64::from((u32::MIN..u32::MAX).fake::<u32>()),
+            (1..6).fake(),
+        )),
+        Type::Bool => Value::Bool(Faker.fake()),
         Type::Optional(_) => {
             if Faker.fake::<bool>() {
-                random_val(&field.non_optional())
+                random_value(&field.non_optional())
             } else {
-                None
+                Value::Null
             }
         }
-        Type::Duration => (0..100).fake::<u64>().to_string().into(),
-        Type::DateTime | Type::Enum => unreachable!("Failed to gen random value for: {field:?}"),
+        Type::Duration => Value::Duration(Duration::seconds((0..100).fake())),
+        #[cfg(feature = "uuid")]
+        Type::Uuid => Value::Uuid(uuid::Uuid::new_v4()),
+        #[cfg(feature = "json")]
+        Type::Json => Value::Json(random_json()),
+        Type::Struct(tp) => Value::Struct(
+            tp.fields()
+                .iter()
+                .filter(|field| can_be_random(field))
+                .map(|field| (field.name.to_string(), random_value(field)))
+                .collect(),
+        ),
+        Type::Enum | Type::Bytes | Type::List(_) | Type::Map { .. } => {
+            unreachable!("Failed to gen random value for: {field:?}")
+        }
     }
 }
+
+/// Value in `0..1_000` which fits into the field type
+fn random_integer(kind: Option<IntegerKind>) -> i128 {
+    let Some(kind) = kind else {
+        return (0..1_000).fake();
+    };
+
+    let min = if kind.non_zero { 1 } else { kind.min().max(0) };
+    let max = i128::try_from(kind.max()).unwrap_or(i128::MAX).min(1_000);
+
+    (min..max).fake()
+}
+
+/// Small object of random string and number values
+#[cfg(feature = "json")]
+fn random_json() -> serde_json::Value {
+    (0..(1..4).fake::<usize>())
+        .map(|index| {
+            let value = if Faker.fake::<bool>() {
+                (0..1_000).fake::<i64>().into()
+            } else {
+                8.fake::<String>().into()
+            };
+            (format!("key_{index}"), value)
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
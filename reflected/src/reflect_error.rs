@@ -0,0 +1,62 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// Error of fallible `Reflected` methods like `try_set_value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectError {
+    pub type_name: &'static str,
+    /// Field name or dotted path
+    pub field:     String,
+    /// Value which failed to convert
+    pub input:     Option<String>,
+    pub kind:      ReflectErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReflectErrorKind {
+    /// `NULL` for non optional field
+    MissingValue,
+    /// Input can't be converted to field type
+    Parse(String),
+    UnknownField,
+}
+
+impl ReflectError {
+    pub fn new(
+        type_name: &'static str,
+        field: impl ToString,
+        input: Option<&str>,
+        kind: ReflectErrorKind,
+    ) -> Self {
+        Self {
+            type_name,
+            field: field.to_string(),
+            input: input.map(ToString::to_string),
+            kind,
+        }
+    }
+}
+
+impl Display for ReflectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { type_name, field, .. } = self;
+
+        match &self.kind {
+            ReflectErrorKind::MissingValue => {
+                write!(
+                    f,
+                    "Trying to set non optional field {type_name}.{field} from NULL"
+                )
+            }
+            ReflectErrorKind::Parse(cause) => match &self.input {
+                Some(input) => write!(f, "Invalid value of {type_name}.{field}: {input:?}. {cause}"),
+                None => write!(f, "Invalid value of {type_name}.{field}. {cause}"),
+            },
+            ReflectErrorKind::UnknownField => write!(f, "Unknown field {field} of {type_name}"),
+        }
+    }
+}
+
+impl Error for ReflectError {}
@@ -1,21 +1,68 @@
-use crate::Field;
+use std::collections::BTreeMap;
+
+use crate::{
+    Field, MapPolicy, MissingKeys, ReflectError, ReflectErrorKind, UnknownKeys, Value,
+    json::{decode_object, encode_object, typed_item},
+    path,
+};
 
 pub trait Reflected: Send + Default + 'static {
+    /// Number of `fields()`
+    const FIELD_COUNT: usize;
+
     fn type_name() -> &'static str;
 
     fn fields() -> &'static [Field<Self>];
 
-    fn get_value(&self, field: Field<Self>) -> String;
-    fn set_value(&mut self, field: Field<Self>, value: Option<&str>);
+    /// Field with `Field::index` of `index`
+    fn field_at(index: usize) -> Option<Field<Self>> {
+        Self::fields().get(index).copied()
+    }
+
+    /// Fields filled by `RandomReflected`
+    fn random_fields() -> &'static [Field<Self>] {
+        Self::fields()
+    }
+
+    fn try_get_value(&self, field: Field<Self>) -> Result<String, ReflectError>;
+    fn try_set_value(&mut self, field: Field<Self>, value: Option<&str>) -> Result<(), ReflectError>;
+
+    /// Panics if `field` is not a field of this type
+    fn get_value(&self, field: Field<Self>) -> String {
+        self.try_get_value(field).unwrap_or_else(|err| panic!("{err}"))
+    }
 
+    /// Panics if `value` can't be converted to field type
+    fn set_value(&mut self, field: Field<Self>, value: Option<&str>) {
+        self.try_set_value(field, value).unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    /// Typed value of the field. Ignores `format` and `encoding` attributes.
+    /// Panics if `field` is not a field of this type
+    fn get(&self, field: Field<Self>) -> Value;
+    fn set(&mut self, field: Field<Self>, value: Value) -> Result<(), ReflectError>;
+
+    /// Binds all fields except `id`, `usize` and `skip_bind` fields. Values
+    /// without Postgres encoding, like nested structs and maps, are bound with
+    /// `ReflectedValue::bind_sqlx_value`
     #[cfg(feature = "sqlx_bind")]
-    fn bind_to_sqlx_query<'q, O>(
-        self,
-        query: sqlx::query::QueryAs<'q, sqlx::Postgres, O, <sqlx::Postgres as sqlx::Database>::Arguments<'q>>,
-    ) -> sqlx::query::QueryAs<'q, sqlx::Postgres, O, <sqlx::Postgres as sqlx::Database>::Arguments<'q>>;
+    fn bind_to_sqlx_query<O>(self, query: crate::SqlxQuery<'_, O>) -> crate::SqlxQuery<'_, O>;
+
+    /// Field by name or `#[reflected(alias = "...")]`
+    fn try_field_by_name(name: &str) -> Option<Field<Self>> {
+        Self::fields().iter().find(|field| field.name == name).copied()
+    }
+
+    /// Field by ASCII case-insensitive name or alias
+    fn try_field_by_name_ignore_case(name: &str) -> Option<Field<Self>> {
+        Self::fields()
+            .iter()
+            .find(|field| field.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
 
     fn field_by_name(name: &str) -> Field<Self> {
-        *Self::fields().iter().find(|a| a.name == name).unwrap_or_else(|| {
+        Self::try_field_by_name(name).unwrap_or_else(|| {
             panic!(
                 "Failed to get field_by_name of {}. Field name: {name}",
                 Self::type_name(),
@@ -26,4 +73,153 @@ pub trait Reflected: Send + Default + 'static {
     fn value_by_name(&self, name: &str) -> String {
         self.get_value(Self::field_by_name(name))
     }
+
+    /// Value of field of nested struct or map entry:
+    /// `user.value_by_path("address.city")`,
+    /// `user.value_by_path("settings.theme")`
+    fn try_value_by_path(&self, path: &str) -> Result<String, ReflectError> {
+        let (name, rest) = path::split(path);
+        let field = path::field_by_name::<Self>(name)?;
+        let value = self.try_get_value(field)?;
+
+        let Some(rest) = rest else {
+            return Ok(value);
+        };
+
+        path::nested_value(field.tp, &value, rest)
+            .map_err(|kind| ReflectError::new(Self::type_name(), path, None, kind))
+    }
+
+    fn try_set_value_by_path(&mut self, path: &str, value: Option<&str>) -> Result<(), ReflectError> {
+        let (name, rest) = path::split(path);
+        let field = path::field_by_name::<Self>(name)?;
+
+        let Some(rest) = rest else {
+            return self.try_set_value(field, value);
+        };
+
+        let nested = path::set_nested_value(field.tp, &self.try_get_value(field)?, rest, value)
+            .map_err(|kind| ReflectError::new(Self::type_name(), path, value, kind))?;
+
+        self.try_set_value(field, Some(&nested))
+    }
+
+    fn value_by_path(&self, path: &str) -> String {
+        self.try_value_by_path(path)
+            .unwrap_or_else(|err| panic!("Failed to get {path} of {}: {err}", Self::type_name()))
+    }
+
+    fn set_value_by_path(&mut self, path: &str, value: Option<&str>) {
+        self.try_set_value_by_path(path, value)
+            .unwrap_or_else(|err| panic!("Failed to set {path} of {}: {err}", Self::type_name()));
+    }
+
+    /// Calls `f` with dotted path and field for every field including fields
+    /// of nested structs
+    fn walk_fields(mut f: impl FnMut(&str, &Field<()>)) {
+        path::walk_fields(Self::fields(), "", &mut f);
+    }
+
+    /// JSON object of all field values. Encoding of `Type::Struct` fields
+    fn to_reflected_json(&self) -> String {
+        encode_object(Self::fields().iter().map(|field| {
+            (
+                field.name.to_string(),
+                typed_item(field.tp, self.get_value(*field)),
+            )
+        }))
+    }
+
+    /// Missing fields keep default values
+    fn from_reflected_json(value: &str) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        for (name, item) in decode_object(value)? {
+            let Some(field) = Self::try_field_by_name(&name) else {
+                return Err(format!("Unknown field of {}: {name}", Self::type_name()));
+            };
+
+            result
+                .try_set_value(field, item.into_typed_value(field.tp).as_deref())
+                .map_err(|err| err.to_string())?;
+        }
+
+        Ok(result)
+    }
+
+    /// Values of all fields by name
+    fn to_map(&self) -> BTreeMap<&'static str, String> {
+        Self::fields()
+            .iter()
+            .map(|field| (field.name, self.get_value(*field)))
+            .collect()
+    }
+
+    /// Errors on unknown keys. Missing fields keep default values
+    fn from_map(map: &BTreeMap<&str, String>) -> Result<Self, ReflectError> {
+        Self::from_map_with(map, MapPolicy::default())
+    }
+
+    fn from_map_with(map: &BTreeMap<&str, String>, policy: MapPolicy) -> Result<Self, ReflectError> {
+        if policy.unknown == UnknownKeys::Error
+            && let Some(key) = map.keys().find(|key| !Self::fields().iter().any(|field| field.name == **key))
+        {
+            return Err(ReflectError::new(
+                Self::type_name(),
+                key,
+                map.get(key).map(String::as_str),
+                ReflectErrorKind::UnknownField,
+            ));
+        }
+
+        let mut result = Self::default();
+
+        for field in Self::fields() {
+            match map.get(field.name) {
+                Some(value) => result.try_set_value(*field, Some(value))?,
+                None if policy.missing == MissingKeys::Error => {
+                    return Err(ReflectError::new(
+                        Self::type_name(),
+                        field.name,
+                        None,
+                        ReflectErrorKind::MissingValue,
+                    ));
+                }
+                None => (),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Values in order of `fields()`. `None` means `NULL`
+    fn from_positional(values: &[Option<&str>]) -> Result<Self, ReflectError> {
+        let fields = Self::fields();
+
+        if let Some(value) = values.get(fields.len()) {
+            return Err(ReflectError::new(
+                Self::type_name(),
+                format!("#{}", fields.len()),
+                *value,
+                ReflectErrorKind::UnknownField,
+            ));
+        }
+
+        if let Some(field) = fields.get(values.len()) {
+            return Err(ReflectError::new(
+                Self::type_name(),
+                field.name,
+                None,
+                ReflectErrorKind::MissingValue,
+            ));
+        }
+
+        let mut result = Self::default();
+
+        for (field, value) in fields.iter().zip(values) {
+            result.try_set_value(*field, *value)?;
+        }
+
+        Ok(result)
+    }
 }
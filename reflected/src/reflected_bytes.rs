@@ -0,0 +1,89 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// String encoding of `Type::Bytes` fields set with
+/// `#[reflected(encoding = "...")]`. `Hex` is used by default
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BytesEncoding {
+    Hex,
+    Base64,
+}
+
+impl BytesEncoding {
+    pub fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Hex => hex::encode(bytes),
+            Self::Base64 => STANDARD.encode(bytes),
+        }
+    }
+
+    pub fn decode(self, value: &str) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Hex => {
+                hex::decode(value).map_err(|err| format!("Failed to decode hex from: {value}. Err: {err}"))
+            }
+            Self::Base64 => STANDARD
+                .decode(value)
+                .map_err(|err| format!("Failed to decode base64 from: {value}. Err: {err}")),
+        }
+    }
+}
+
+/// Binary field conversion with `BytesEncoding`
+pub trait ReflectedBytes: Sized {
+    fn encode_reflected_bytes(&self, encoding: BytesEncoding) -> String;
+    fn decode_reflected_bytes(value: &str, encoding: BytesEncoding) -> Result<Self, String>;
+}
+
+impl ReflectedBytes for Vec<u8> {
+    fn encode_reflected_bytes(&self, encoding: BytesEncoding) -> String {
+        encoding.encode(self)
+    }
+
+    fn decode_reflected_bytes(value: &str, encoding: BytesEncoding) -> Result<Self, String> {
+        encoding.decode(value)
+    }
+}
+
+impl<const N: usize> ReflectedBytes for [u8; N] {
+    fn encode_reflected_bytes(&self, encoding: BytesEncoding) -> String {
+        encoding.encode(self)
+    }
+
+    fn decode_reflected_bytes(value: &str, encoding: BytesEncoding) -> Result<Self, String> {
+        encoding.decode(value)?.try_into().map_err(|bytes: Vec<u8>| {
+            format!(
+                "Invalid bytes length of: {value}. Expected: {N}, got: {}",
+                bytes.len()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BytesEncoding, ReflectedBytes};
+
+    #[test]
+    fn encoding() {
+        let bytes = vec![0_u8, 15, 255];
+
+        assert_eq!(bytes.encode_reflected_bytes(BytesEncoding::Hex), "000fff");
+        assert_eq!(bytes.encode_reflected_bytes(BytesEncoding::Base64), "AA//");
+
+        assert_eq!(
+            Vec::decode_reflected_bytes("000fff", BytesEncoding::Hex),
+            Ok(bytes.clone())
+        );
+        assert_eq!(
+            Vec::decode_reflected_bytes("AA//", BytesEncoding::Base64),
+            Ok(bytes)
+        );
+
+        assert_eq!(
+            <[u8; 2]>::decode_reflected_bytes("0aff", BytesEncoding::Hex),
+            Ok([10, 255])
+        );
+        assert!(<[u8; 3]>::decode_reflected_bytes("0aff", BytesEncoding::Hex).is_err());
+        assert!(Vec::decode_reflected_bytes("xyz", BytesEncoding::Hex).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// Date conversion with custom `chrono` format set with
+/// `#[reflected(format = "...")]`
+pub trait ReflectedDate: Sized {
+    fn format_reflected_date(&self, format: &str) -> String;
+    fn parse_reflected_date(value: &str, format: &str) -> Result<Self, String>;
+}
+
+macro_rules! impl_reflected_date {
+    ($($t:ty),*) => {$(
+        impl ReflectedDate for $t {
+            fn format_reflected_date(&self, format: &str) -> String {
+                self.format(format).to_string()
+            }
+
+            fn parse_reflected_date(value: &str, format: &str) -> Result<Self, String> {
+                <$t>::parse_from_str(value, format).map_err(|err| parse_error(value, format, err))
+            }
+        }
+    )*};
+}
+
+impl_reflected_date!(NaiveDateTime, NaiveDate, NaiveTime);
+
+impl ReflectedDate for DateTime<FixedOffset> {
+    fn format_reflected_date(&self, format: &str) -> String {
+        self.format(format).to_string()
+    }
+
+    fn parse_reflected_date(value: &str, format: &str) -> Result<Self, String> {
+        DateTime::parse_from_str(value, format).map_err(|err| parse_error(value, format, err))
+    }
+}
+
+impl ReflectedDate for DateTime<Utc> {
+    fn format_reflected_date(&self, format: &str) -> String {
+        self.format(format).to_string()
+    }
+
+    /// Formats without offset are parsed as UTC
+    fn parse_reflected_date(value: &str, format: &str) -> Result<Self, String> {
+        DateTime::parse_from_str(value, format)
+            .map(|date| date.to_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(value, format).map(|date| date.and_utc()))
+            .map_err(|err| parse_error(value, format, err))
+    }
+}
+
+fn parse_error(value: &str, format: &str, err: chrono::ParseError) -> String {
+    format!("Failed to parse date from: {value} with format: {format}. Err: {err}")
+}
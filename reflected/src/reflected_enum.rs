@@ -0,0 +1,10 @@
+pub trait ReflectedEnum: Sized + 'static {
+    fn variants() -> &'static [Self];
+    fn variant_names() -> &'static [&'static str];
+
+    fn variant_name(&self) -> &'static str;
+    fn from_variant_name(name: &str) -> Option<Self>;
+
+    fn discriminant(&self) -> isize;
+    fn from_discriminant(discriminant: isize) -> Option<Self>;
+}
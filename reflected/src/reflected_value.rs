@@ -0,0 +1,500 @@
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::{
+    BytesEncoding, IntegerKind, ToReflectedString, ToReflectedVal, Type, Value, list, value::mismatch,
+};
+#[cfg(feature = "sqlx_bind")]
+use crate::{
+    SqlxQuery,
+    sqlx_bind::{bind_array, bind_integer},
+};
+
+/// Type of a `Reflected` struct field. Implement it to use custom types as
+/// fields:
+///
+/// ```
+/// use reflected::{ReflectedValue, Type};
+///
+/// #[derive(Default)]
+/// struct Email(String);
+///
+/// impl ReflectedValue for Email {
+///     const TYPE: Type = Type::Text;
+///
+///     fn to_reflected_value(&self) -> String {
+///         self.0.clone()
+///     }
+///
+///     fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+///         Ok(Self(value.ok_or("Email can't be NULL")?.to_string()))
+///     }
+/// }
+/// ```
+pub trait ReflectedValue: Sized {
+    const TYPE: Type;
+
+    /// `true` if `NULL` is a valid value
+    const OPTIONAL: bool = false;
+
+    /// Width and signedness of integer types
+    const INTEGER_KIND: Option<IntegerKind> = None;
+
+    /// `RandomReflected` can generate values of the type from `TYPE`. Fields of
+    /// custom types keep default values unless they set it
+    const RANDOM: bool = false;
+
+    /// Value returned by `Reflected::get_value`
+    fn to_reflected_value(&self) -> String;
+
+    /// Value passed to `Reflected::set_value`. `None` means `NULL`
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String>;
+
+    /// Value returned by `Reflected::get`. Parsed from `to_reflected_value` by
+    /// default
+    fn to_value(&self) -> Value {
+        Value::from_reflected(Self::TYPE, Some(&self.to_reflected_value()))
+            .unwrap_or_else(|err| panic!("Invalid reflected value of {:?}: {err}", Self::TYPE))
+    }
+
+    /// Value passed to `Reflected::set`. Converted with
+    /// `from_reflected_value` by default
+    fn from_value(value: Value) -> Result<Self, String> {
+        Self::from_reflected_value(value.to_reflected().as_deref())
+    }
+
+    /// Binds values of types without Postgres encoding in
+    /// `Reflected::bind_to_sqlx_query`. `None` means `NULL`. Binds
+    /// `to_reflected_value` as text by default
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        query.bind(value.map(Self::to_reflected_value))
+    }
+
+    /// `Type` of `Vec<Self>` and `[Self; N]`. `Type::Bytes` for `u8`
+    #[doc(hidden)]
+    const LIST_TYPE: Type = Type::List(&Self::TYPE);
+
+    #[doc(hidden)]
+    fn encode_list(items: &[Self]) -> String {
+        list::encode(items)
+    }
+
+    #[doc(hidden)]
+    fn decode_list(value: &str) -> Result<Vec<Self>, String> {
+        list::decode(value)
+    }
+
+    #[doc(hidden)]
+    fn list_to_value(items: &[Self]) -> Value {
+        list::to_value(items)
+    }
+
+    #[doc(hidden)]
+    fn list_from_value(value: Value) -> Result<Vec<Self>, String> {
+        list::from_value(value)
+    }
+
+    /// Binds items of `Vec<Self>`, `[Self; N]` and `HashSet<Self>` values
+    /// without Postgres encoding. `None` means `NULL`. Binds JSON array text
+    /// by default
+    #[doc(hidden)]
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        query.bind(items.map(list::encode))
+    }
+}
+
+pub(crate) fn non_null(value: Option<&str>) -> Result<&str, String> {
+    value.ok_or_else(|| "Trying to set non optional field from NULL".to_string())
+}
+
+macro_rules! impl_reflected_value {
+    ($tp:ident, $to_string:ident, $($t:ty),*) => {$(
+        impl ReflectedValue for $t {
+            const TYPE: Type = Type::$tp;
+            const RANDOM: bool = true;
+
+            fn to_reflected_value(&self) -> String {
+                self.$to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                ToReflectedVal::<$t>::to_reflected_val(&non_null(value)?)
+            }
+
+            fn to_value(&self) -> Value {
+                Value::$tp(self.clone())
+            }
+
+            fn from_value(value: Value) -> Result<Self, String> {
+                match value {
+                    Value::$tp(value) => Ok(value),
+                    value => mismatch(stringify!($tp), &value),
+                }
+            }
+
+            #[cfg(feature = "sqlx_bind")]
+            fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                bind_array(items, query, Clone::clone)
+            }
+        }
+    )*};
+}
+
+macro_rules! integer_value {
+    (true, $value:expr) => {
+        Value::Int($value as i128)
+    };
+    (false, $value:expr) => {
+        Value::from_unsigned($value as u128)
+    };
+}
+
+/// `$t => $sql` binds values as Postgres integer type `$sql`. Types without it
+/// can't be bound
+macro_rules! impl_reflected_integer {
+    ($signed:tt, $($t:ty $(=> $sql:ty)?),*) => {$(
+        impl ReflectedValue for $t {
+            const TYPE: Type = Type::Integer;
+            const INTEGER_KIND: Option<IntegerKind> = Some(IntegerKind::new(<$t>::BITS, $signed));
+            const RANDOM: bool = true;
+
+            fn to_reflected_value(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                ToReflectedVal::<$t>::to_reflected_val(&non_null(value)?)
+            }
+
+            // `isize` and `usize` have no `From` conversions to 128 bit integers
+            #[allow(clippy::cast_lossless)]
+            fn to_value(&self) -> Value {
+                integer_value!($signed, *self)
+            }
+
+            fn from_value(value: Value) -> Result<Self, String> {
+                value.into_integer()
+            }
+
+            $(
+                #[cfg(feature = "sqlx_bind")]
+                fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                    bind_integer::<$sql, _, _>(value, query)
+                }
+
+                #[cfg(feature = "sqlx_bind")]
+                fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                    bind_array(items, query, |item| <$sql>::from(*item))
+                }
+            )?
+        }
+    )*};
+}
+
+macro_rules! impl_reflected_float {
+    ($($t:ty),*) => {$(
+        impl ReflectedValue for $t {
+            const TYPE: Type = Type::Float;
+            const RANDOM: bool = true;
+
+            fn to_reflected_value(&self) -> String {
+                self.to_reflected_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                ToReflectedVal::<$t>::to_reflected_val(&non_null(value)?)
+            }
+
+            fn to_value(&self) -> Value {
+                Value::Float(f64::from(*self))
+            }
+
+            // Nearest value like in `from_reflected_value`
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            fn from_value(value: Value) -> Result<Self, String> {
+                match value {
+                    Value::Float(value) => Ok(value as $t),
+                    Value::Int(value) => Ok(value as $t),
+                    value => mismatch("Float", &value),
+                }
+            }
+
+            #[cfg(feature = "sqlx_bind")]
+            fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                bind_array(items, query, |item| *item)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_reflected_non_zero {
+    ($signed:literal, $($t:ty $(=> $sql:ty)?),*) => {$(
+        impl ReflectedValue for $t {
+            const TYPE: Type = Type::Integer;
+            const INTEGER_KIND: Option<IntegerKind> =
+                Some(IntegerKind::new(<$t>::BITS, $signed).non_zero());
+            const RANDOM: bool = true;
+
+            fn to_reflected_value(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                ToReflectedVal::<$t>::to_reflected_val(&non_null(value)?)
+            }
+
+            $(
+                #[cfg(feature = "sqlx_bind")]
+                fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                    bind_integer::<$sql, _, _>(value.map(|value| value.get()).as_ref(), query)
+                }
+
+                #[cfg(feature = "sqlx_bind")]
+                fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+                    bind_array(items, query, |item| <$sql>::from(item.get()))
+                }
+            )?
+        }
+    )*};
+}
+
+impl_reflected_integer!(true, i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128, isize);
+impl_reflected_integer!(false, u16 => i32, u32 => i64, u64 => Decimal, u128, usize);
+impl_reflected_non_zero!(
+    true,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128,
+    NonZeroIsize
+);
+impl_reflected_non_zero!(
+    false,
+    NonZeroU8 => i16,
+    NonZeroU16 => i32,
+    NonZeroU32 => i64,
+    NonZeroU64 => Decimal,
+    NonZeroU128,
+    NonZeroUsize
+);
+impl_reflected_float!(f32, f64);
+impl_reflected_value!(Text, to_string, String);
+impl_reflected_value!(Decimal, to_string, Decimal);
+impl_reflected_value!(Date, to_reflected_string, NaiveDateTime);
+impl_reflected_value!(NaiveDate, to_string, NaiveDate);
+impl_reflected_value!(NaiveTime, to_string, NaiveTime);
+impl_reflected_value!(Duration, to_reflected_string, Duration);
+#[cfg(feature = "uuid")]
+impl_reflected_value!(Uuid, to_string, uuid::Uuid);
+#[cfg(feature = "json")]
+impl_reflected_value!(Json, to_string, serde_json::Value);
+
+impl ReflectedValue for DateTime<Utc> {
+    const TYPE: Type = Type::DateTime;
+    const RANDOM: bool = true;
+
+    fn to_reflected_value(&self) -> String {
+        self.to_reflected_string()
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        ToReflectedVal::<Self>::to_reflected_val(&non_null(value)?)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::DateTime(self.fixed_offset())
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        DateTime::<FixedOffset>::from_value(value).map(|date| date.to_utc())
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        bind_array(items, query, |item| *item)
+    }
+}
+
+impl ReflectedValue for DateTime<FixedOffset> {
+    const TYPE: Type = Type::DateTime;
+    const RANDOM: bool = true;
+
+    fn to_reflected_value(&self) -> String {
+        self.to_reflected_string()
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        ToReflectedVal::<Self>::to_reflected_val(&non_null(value)?)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::DateTime(*self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::DateTime(value) => Ok(value),
+            value => mismatch("DateTime", &value),
+        }
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        bind_array(items, query, |item| *item)
+    }
+}
+
+/// Lists of `u8` are `Type::Bytes`
+impl ReflectedValue for u8 {
+    const TYPE: Type = Type::Integer;
+    const INTEGER_KIND: Option<IntegerKind> = Some(IntegerKind::new(u8::BITS, false));
+    const RANDOM: bool = true;
+    const LIST_TYPE: Type = Type::Bytes;
+
+    fn to_reflected_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        ToReflectedVal::<u8>::to_reflected_val(&non_null(value)?)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Int(i128::from(*self))
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        value.into_integer()
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        bind_integer::<i16, _, _>(value, query)
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        bind_array(items, query, |item| i16::from(*item))
+    }
+
+    fn encode_list(items: &[Self]) -> String {
+        BytesEncoding::Hex.encode(items)
+    }
+
+    fn decode_list(value: &str) -> Result<Vec<Self>, String> {
+        BytesEncoding::Hex.decode(value)
+    }
+
+    fn list_to_value(items: &[Self]) -> Value {
+        Value::Bytes(items.to_vec())
+    }
+
+    fn list_from_value(value: Value) -> Result<Vec<Self>, String> {
+        let Value::Bytes(bytes) = value else {
+            return Err(format!("Expected Bytes value, got: {value:?}"));
+        };
+        Ok(bytes)
+    }
+}
+
+impl ReflectedValue for bool {
+    const TYPE: Type = Type::Bool;
+    const RANDOM: bool = true;
+
+    fn to_reflected_value(&self) -> String {
+        if *self { "1" } else { "0" }.to_string()
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        match non_null(value)? {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            value => Err(format!("Invalid value in bool: {value}")),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Bool(value) => Ok(value),
+            value => mismatch("Bool", &value),
+        }
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_list<'q, O>(items: Option<Vec<&Self>>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        bind_array(items, query, |item| *item)
+    }
+}
+
+impl<T: ReflectedValue> ReflectedValue for Option<T> {
+    const TYPE: Type = T::TYPE.to_optional();
+    const OPTIONAL: bool = true;
+    const INTEGER_KIND: Option<IntegerKind> = T::INTEGER_KIND;
+    const RANDOM: bool = T::RANDOM;
+
+    fn to_reflected_value(&self) -> String {
+        self.as_ref().map_or("NULL".to_string(), ReflectedValue::to_reflected_value)
+    }
+
+    fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+        match value {
+            None | Some("NULL") => Ok(None),
+            value => T::from_reflected_value(value).map(Some),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ReflectedValue::to_value)
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+
+    #[cfg(feature = "sqlx_bind")]
+    fn bind_sqlx_value<'q, O>(value: Option<&Self>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        T::bind_sqlx_value(value.and_then(Option::as_ref), query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ReflectedValue, Type};
+
+    #[test]
+    fn encoding() {
+        assert_eq!(5_i64.to_reflected_value(), "5");
+        assert_eq!(5.0_f64.to_reflected_value(), "5.0");
+        assert_eq!(true.to_reflected_value(), "1");
+        assert_eq!(None::<i32>.to_reflected_value(), "NULL");
+        assert_eq!(Some(false).to_reflected_value(), "0");
+
+        assert_eq!(i64::from_reflected_value(Some("5")), Ok(5));
+        assert_eq!(bool::from_reflected_value(Some("0")), Ok(false));
+        assert_eq!(Option::<u8>::from_reflected_value(Some("NULL")), Ok(None));
+        assert_eq!(Option::<u8>::from_reflected_value(None), Ok(None));
+        assert_eq!(Option::<u8>::from_reflected_value(Some("7")), Ok(Some(7)));
+
+        assert!(i64::from_reflected_value(None).is_err());
+        assert!(bool::from_reflected_value(Some("true")).is_err());
+
+        assert_eq!(<Option<String>>::TYPE, Type::Text.to_optional());
+        const { assert!(<Option<String>>::OPTIONAL) };
+        const { assert!(!String::OPTIONAL) };
+    }
+}
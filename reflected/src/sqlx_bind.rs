@@ -0,0 +1,60 @@
+use sqlx::{
+    Encode, Postgres, Type,
+    postgres::{PgArguments, PgHasArrayType},
+    query::QueryAs,
+};
+
+use crate::ReflectedValue;
+
+/// Query passed to `Reflected::bind_to_sqlx_query`
+pub type SqlxQuery<'q, O> = QueryAs<'q, Postgres, O, PgArguments>;
+
+/// Field value bound by generated `bind_to_sqlx_query`. Values of types with
+/// Postgres encoding are bound with `BindNative`, other values with
+/// `ReflectedValue::bind_sqlx_value`
+#[doc(hidden)]
+pub struct BindValue<T>(pub T);
+
+#[doc(hidden)]
+pub trait BindNative<'q> {
+    fn bind<O>(self, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O>;
+}
+
+impl<'q, T: 'q + Encode<'q, Postgres> + Type<Postgres>> BindNative<'q> for BindValue<T> {
+    fn bind<O>(self, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        query.bind(self.0)
+    }
+}
+
+/// Used when `BindNative` is not implemented. Method lookup tries `BindValue`
+/// before `&BindValue`
+#[doc(hidden)]
+pub trait BindReflected<'q> {
+    fn bind<O>(self, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O>;
+}
+
+impl<'q, T: ReflectedValue> BindReflected<'q> for &BindValue<T> {
+    fn bind<O>(self, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O> {
+        T::bind_sqlx_value(Some(&self.0), query)
+    }
+}
+
+/// Binds integer `value` as Postgres type `P` which can hold all its values
+pub(crate) fn bind_integer<'q, P, T, O>(value: Option<&T>, query: SqlxQuery<'q, O>) -> SqlxQuery<'q, O>
+where
+    P: From<T> + 'q + Encode<'q, Postgres> + Type<Postgres>,
+    T: Copy, {
+    query.bind(value.map(|value| P::from(*value)))
+}
+
+/// Binds `items` converted with `convert` as Postgres array
+pub(crate) fn bind_array<'q, T, P, O>(
+    items: Option<Vec<&T>>,
+    query: SqlxQuery<'q, O>,
+    convert: impl Fn(&T) -> P,
+) -> SqlxQuery<'q, O>
+where
+    P: 'q + Encode<'q, Postgres> + Type<Postgres> + PgHasArrayType,
+{
+    query.bind(items.map(|items| items.into_iter().map(convert).collect::<Vec<_>>()))
+}
@@ -0,0 +1,63 @@
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::{Field, path};
+
+/// Schema of a type which derives `Reflected`. `fields` is a function so
+/// recursive structs can refer to their own schema
+#[derive(Copy, Clone)]
+pub struct StructType {
+    pub name:   &'static str,
+    pub fields: fn() -> &'static [Field<()>],
+}
+
+impl StructType {
+    pub fn fields(&self) -> &'static [Field<()>] {
+        (self.fields)()
+    }
+
+    pub fn field(&self, name: &str) -> Option<&'static Field<()>> {
+        self.fields().iter().find(|field| field.name == name)
+    }
+
+    /// Calls `f` with dotted path and field for every field including fields
+    /// of nested structs
+    pub fn walk_fields(&self, mut f: impl FnMut(&str, &Field<()>)) {
+        path::walk_fields(self.fields(), "", &mut f);
+    }
+
+    /// Names and type names of fields. Field types are not followed since
+    /// they can lead back to this struct
+    fn shallow_fields(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        self.fields().iter().map(|field| (field.name, field.type_name))
+    }
+}
+
+impl PartialEq for StructType {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.shallow_fields().eq(other.shallow_fields())
+    }
+}
+
+impl Eq for StructType {}
+
+impl Hash for StructType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.shallow_fields().for_each(|field| field.hash(state));
+    }
+}
+
+impl Debug for StructType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructType")
+            .field("name", &self.name)
+            .field(
+                "fields",
+                &self.shallow_fields().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
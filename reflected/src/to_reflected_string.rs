@@ -1,6 +1,13 @@
-use chrono::{Duration, NaiveDateTime};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rust_decimal::{Decimal, prelude::Zero};
 
+use crate::ReflectedEnum;
+
 pub trait ToReflectedString {
     fn to_reflected_string(&self) -> String;
 }
@@ -24,12 +31,27 @@ impl_to_string_optional!(
     u32,
     i64,
     u64,
+    i128,
+    u128,
     isize,
     usize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
     &str,
     String,
     Decimal,
-    NaiveDateTime
+    NaiveDate,
+    NaiveTime
 );
 
 macro_rules! impl_custom_to_string_optional {
@@ -62,10 +84,64 @@ impl ToReflectedString for f32 {
     }
 }
 
+/// ISO 8601 format of `NaiveDateTime` values
+pub(crate) const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+impl ToReflectedString for NaiveDateTime {
+    fn to_reflected_string(&self) -> String {
+        self.format(DATE_FORMAT).to_string()
+    }
+}
+
 impl ToReflectedString for Duration {
     fn to_reflected_string(&self) -> String {
         self.num_seconds().to_string()
     }
 }
 
-impl_custom_to_string_optional!(f64, f32, Duration);
+impl ToReflectedString for DateTime<Utc> {
+    fn to_reflected_string(&self) -> String {
+        self.to_rfc3339()
+    }
+}
+
+impl ToReflectedString for DateTime<FixedOffset> {
+    fn to_reflected_string(&self) -> String {
+        self.to_rfc3339()
+    }
+}
+
+impl_custom_to_string_optional!(
+    f64,
+    f32,
+    NaiveDateTime,
+    Duration,
+    DateTime<Utc>,
+    DateTime<FixedOffset>
+);
+
+#[cfg(feature = "uuid")]
+impl ToReflectedString for uuid::Uuid {
+    fn to_reflected_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl_to_string_optional!(uuid::Uuid);
+
+#[cfg(feature = "json")]
+impl ToReflectedString for serde_json::Value {
+    fn to_reflected_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "json")]
+impl_to_string_optional!(serde_json::Value);
+
+impl<T: ReflectedEnum> ToReflectedString for Option<T> {
+    fn to_reflected_string(&self) -> String {
+        self.as_ref().map_or("NULL".to_string(), |a| a.variant_name().to_string())
+    }
+}
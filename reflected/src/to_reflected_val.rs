@@ -1,8 +1,16 @@
-use std::str::FromStr;
+use std::{
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16,
+        NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    },
+    str::FromStr,
+};
 
-use chrono::{Duration, NaiveDateTime};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rust_decimal::Decimal;
 
+use crate::{ReflectedEnum, to_reflected_string::DATE_FORMAT};
+
 pub trait ToReflectedVal<T> {
     fn to_reflected_val(&self) -> Result<T, String>;
 }
@@ -36,13 +44,90 @@ impl_to_reflected_val!(
     u32,
     i64,
     u64,
+    i128,
+    u128,
     f32,
     f64,
     isize,
     usize,
     String,
     Decimal,
-    NaiveDateTime
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+#[cfg(feature = "uuid")]
+impl_to_reflected_val!(uuid::Uuid);
+
+#[cfg(feature = "json")]
+impl_to_reflected_val!(serde_json::Value);
+
+/// Format of `NaiveDateTime::to_string`
+const DISPLAY_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+impl ToReflectedVal<NaiveDateTime> for &str {
+    fn to_reflected_val(&self) -> Result<NaiveDateTime, String> {
+        NaiveDateTime::parse_from_str(self, DATE_FORMAT)
+            .or_else(|_| NaiveDateTime::parse_from_str(self, DISPLAY_DATE_FORMAT))
+            .or_else(|_| NaiveDateTime::from_str(self))
+            .map_err(|err| format!("Failed to parse date from: {self}. Err: {err}"))
+    }
+}
+
+impl ToReflectedVal<NaiveDate> for &str {
+    fn to_reflected_val(&self) -> Result<NaiveDate, String> {
+        NaiveDate::from_str(self).map_err(|err| format!("Failed to parse date from: {self}. Err: {err}"))
+    }
+}
+
+impl ToReflectedVal<NaiveTime> for &str {
+    fn to_reflected_val(&self) -> Result<NaiveTime, String> {
+        NaiveTime::from_str(self).map_err(|err| format!("Failed to parse time from: {self}. Err: {err}"))
+    }
+}
+
+impl ToReflectedVal<DateTime<FixedOffset>> for &str {
+    fn to_reflected_val(&self) -> Result<DateTime<FixedOffset>, String> {
+        DateTime::parse_from_rfc3339(self)
+            .map_err(|err| format!("Failed to parse RFC 3339 date time from: {self}. Err: {err}"))
+    }
+}
+
+impl ToReflectedVal<DateTime<Utc>> for &str {
+    fn to_reflected_val(&self) -> Result<DateTime<Utc>, String> {
+        ToReflectedVal::<DateTime<FixedOffset>>::to_reflected_val(self).map(|date| date.to_utc())
+    }
+}
+
+macro_rules! impl_to_reflected_val_optional {
+    ($($t:ty),*) => {$(
+        impl ToReflectedVal<Option<$t>> for &str {
+            fn to_reflected_val(&self) -> Result<Option<$t>, String> {
+                if *self == "NULL" {
+                    return Ok(None);
+                }
+                ToReflectedVal::<$t>::to_reflected_val(self).map(Some)
+            }
+        }
+    )*};
+}
+
+impl_to_reflected_val_optional!(
+    NaiveDateTime,
+    NaiveDate,
+    NaiveTime,
+    DateTime<FixedOffset>,
+    DateTime<Utc>
 );
 
 impl ToReflectedVal<Duration> for &str {
@@ -54,3 +139,14 @@ impl ToReflectedVal<Duration> for &str {
         Ok(Duration::new(seconds, 0).unwrap_or_else(|| panic!("Failed to create Duration from {seconds}")))
     }
 }
+
+impl<T: ReflectedEnum> ToReflectedVal<Option<T>> for &str {
+    fn to_reflected_val(&self) -> Result<Option<T>, String> {
+        if *self == "NULL" {
+            return Ok(None);
+        }
+        T::from_variant_name(self)
+            .map(Some)
+            .ok_or_else(|| format!("Failed to parse enum variant from {self}"))
+    }
+}
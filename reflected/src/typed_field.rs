@@ -0,0 +1,57 @@
+use std::{
+    fmt::{Debug, Formatter},
+    ops::Deref,
+};
+
+use crate::Field;
+
+/// Field of `T` with value type `V`. Generated by `Reflected` derive as
+/// consts of `{Name}Fields` struct: `UserFields::NAME.get(&user)`
+pub struct TypedField<T, V> {
+    pub field: Field<T>,
+    get:       for<'a> fn(&'a T) -> &'a V,
+    get_mut:   for<'a> fn(&'a mut T) -> &'a mut V,
+}
+
+impl<T, V> Clone for TypedField<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, V> Copy for TypedField<T, V> {}
+
+impl<T, V> TypedField<T, V> {
+    pub const fn new(
+        field: Field<T>,
+        get: for<'a> fn(&'a T) -> &'a V,
+        get_mut: for<'a> fn(&'a mut T) -> &'a mut V,
+    ) -> Self {
+        Self { field, get, get_mut }
+    }
+
+    pub fn get<'a>(&self, obj: &'a T) -> &'a V {
+        (self.get)(obj)
+    }
+
+    pub fn get_mut<'a>(&self, obj: &'a mut T) -> &'a mut V {
+        (self.get_mut)(obj)
+    }
+
+    pub fn set(&self, obj: &mut T, value: V) {
+        *self.get_mut(obj) = value;
+    }
+}
+
+impl<T, V> Debug for TypedField<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TypedField {{ field: {:?} }}", self.field)
+    }
+}
+
+impl<T, V> Deref for TypedField<T, V> {
+    type Target = Field<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.field
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use rust_decimal::Decimal;
+
+use crate::{
+    BytesEncoding, ToReflectedString, ToReflectedVal, Type,
+    json::{JsonItem, decode_array, decode_object, encode_array, encode_object, typed_item},
+};
+
+/// Typed field value used by `Reflected::get` and `Reflected::set`
+/// `Uuid` and `Json` variants exist only with their features
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    Null,
+    Int(i128),
+    /// Unsigned values which don't fit `i128`
+    UInt(u128),
+    Float(f64),
+    Text(String),
+    Decimal(Decimal),
+    Bool(bool),
+    /// Variant name
+    Enum(String),
+    Date(NaiveDateTime),
+    NaiveDate(NaiveDate),
+    NaiveTime(NaiveTime),
+    DateTime(DateTime<FixedOffset>),
+    Duration(Duration),
+    Bytes(Vec<u8>),
+    List(Vec<Value>),
+    Map(BTreeMap<String, Value>),
+    /// Field names and values in declaration order
+    Struct(Vec<(String, Value)>),
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
+    #[cfg(feature = "json")]
+    Json(serde_json::Value),
+}
+
+impl Value {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// `Int` or `UInt` if value doesn't fit `i128`
+    pub fn from_unsigned(value: u128) -> Self {
+        i128::try_from(value).map_or(Self::UInt(value), Self::Int)
+    }
+
+    /// Value of reflected string `value` of type `tp`. `None` and `NULL` of
+    /// optional types are `Null`
+    pub fn from_reflected(tp: Type, value: Option<&str>) -> Result<Self, String> {
+        let Some(value) = value else {
+            return Ok(Self::Null);
+        };
+
+        let tp = match tp {
+            Type::Optional(_) if value == "NULL" => return Ok(Self::Null),
+            Type::Optional(tp) => tp.to_non_optional(),
+            tp => tp,
+        };
+
+        Ok(match tp {
+            Type::Integer => value
+                .parse()
+                .map(Self::Int)
+                .or_else(|_| value.parse().map(Self::UInt))
+                .map_err(|_| format!("Failed to parse integer from: {value}"))?,
+            Type::Float => Self::Float(value.to_reflected_val()?),
+            Type::Text => Self::Text(value.to_string()),
+            Type::Decimal => Self::Decimal(value.to_reflected_val()?),
+            Type::Bool => match value {
+                "0" => Self::Bool(false),
+                "1" => Self::Bool(true),
+                value => return Err(format!("Invalid value in bool: {value}")),
+            },
+            Type::Enum => Self::Enum(value.to_string()),
+            Type::Date => Self::Date(value.to_reflected_val()?),
+            Type::NaiveDate => Self::NaiveDate(value.to_reflected_val()?),
+            Type::NaiveTime => Self::NaiveTime(value.to_reflected_val()?),
+            Type::DateTime => Self::DateTime(value.to_reflected_val()?),
+            Type::Duration => Self::Duration(value.to_reflected_val()?),
+            Type::Bytes => Self::Bytes(BytesEncoding::Hex.decode(value)?),
+            Type::List(element) => Self::List(
+                decode_array(value)?
+                    .into_iter()
+                    .map(|item| Self::from_reflected(*element, item.into_typed_value(*element).as_deref()))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::Map { value: element, .. } => Self::Map(
+                decode_object(value)?
+                    .into_iter()
+                    .map(|(key, item)| {
+                        Ok((
+                            key,
+                            Self::from_reflected(*element, item.into_typed_value(*element).as_deref())?,
+                        ))
+                    })
+                    .collect::<Result<_, String>>()?,
+            ),
+            Type::Struct(tp) => Self::Struct(
+                decode_object(value)?
+                    .into_iter()
+                    .map(|(key, item)| {
+                        let field =
+                            tp.field(&key).ok_or_else(|| format!("Unknown field {key} of {}", tp.name))?;
+                        Ok((
+                            key,
+                            Self::from_reflected(field.tp, item.into_typed_value(field.tp).as_deref())?,
+                        ))
+                    })
+                    .collect::<Result<_, String>>()?,
+            ),
+            #[cfg(feature = "uuid")]
+            Type::Uuid => Self::Uuid(value.to_reflected_val()?),
+            #[cfg(feature = "json")]
+            Type::Json => Self::Json(value.to_reflected_val()?),
+            Type::Optional(_) => unreachable!("Optional type is unwrapped above"),
+        })
+    }
+
+    /// Reflected string of the value. `None` for `Null`
+    pub fn to_reflected(&self) -> Option<String> {
+        Some(match self {
+            Self::Null => return None,
+            Self::Int(value) => value.to_string(),
+            Self::UInt(value) => value.to_string(),
+            Self::Float(value) => value.to_reflected_string(),
+            Self::Text(value) | Self::Enum(value) => value.clone(),
+            Self::Decimal(value) => value.to_string(),
+            Self::Bool(value) => if *value { "1" } else { "0" }.to_string(),
+            Self::Date(value) => value.to_reflected_string(),
+            Self::NaiveDate(value) => value.to_string(),
+            Self::NaiveTime(value) => value.to_string(),
+            Self::DateTime(value) => value.to_reflected_string(),
+            Self::Duration(value) => value.to_reflected_string(),
+            Self::Bytes(value) => BytesEncoding::Hex.encode(value),
+            Self::List(items) => encode_array(items.iter().map(Self::to_json_item)),
+            Self::Map(entries) => {
+                encode_object(entries.iter().map(|(key, value)| (key.clone(), value.to_json_item())))
+            }
+            Self::Struct(fields) => {
+                encode_object(fields.iter().map(|(name, value)| (name.clone(), value.to_json_item())))
+            }
+            #[cfg(feature = "uuid")]
+            Self::Uuid(value) => value.to_string(),
+            #[cfg(feature = "json")]
+            Self::Json(value) => value.to_string(),
+        })
+    }
+
+    fn to_json_item(&self) -> JsonItem {
+        let Some(value) = self.to_reflected() else {
+            return JsonItem::Null;
+        };
+
+        match self {
+            Self::Float(_) => typed_item(Type::Float, value),
+            Self::Bool(_) => typed_item(Type::Bool, value),
+            Self::Int(_)
+            | Self::UInt(_)
+            | Self::Decimal(_)
+            | Self::List(_)
+            | Self::Map(_)
+            | Self::Struct(_) => JsonItem::Raw(value),
+            #[cfg(feature = "json")]
+            Self::Json(_) => JsonItem::Raw(value),
+            _ => JsonItem::Str(value),
+        }
+    }
+
+    /// Integer of type `T` from `Int` or `UInt`
+    pub(crate) fn into_integer<T: TryFrom<i128> + TryFrom<u128>>(self) -> Result<T, String> {
+        match self {
+            Self::Int(value) => T::try_from(value).map_err(|_| out_of_range(&self)),
+            Self::UInt(value) => T::try_from(value).map_err(|_| out_of_range(&self)),
+            value => mismatch("Int", &value),
+        }
+    }
+}
+
+/// Error of setting field from value of other type
+pub(crate) fn mismatch<T>(expected: &str, value: &Value) -> Result<T, String> {
+    if value.is_null() {
+        return Err("Trying to set non optional field from NULL".to_string());
+    }
+
+    Err(format!("Expected {expected} value, got: {value:?}"))
+}
+
+fn out_of_range(value: &Value) -> String {
+    format!("Integer value out of range: {value:?}")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ReflectedValue, Type, Value};
+
+    #[test]
+    fn reflected() {
+        assert_eq!(
+            Value::from_reflected(Type::Integer, Some("-5")),
+            Ok(Value::Int(-5))
+        );
+        assert_eq!(
+            Value::from_reflected(Type::Integer, Some(&u128::MAX.to_string())),
+            Ok(Value::UInt(u128::MAX))
+        );
+        assert_eq!(
+            Value::from_reflected(Type::Text, Some("NULL")),
+            Ok(Value::Text("NULL".to_string()))
+        );
+        assert_eq!(
+            Value::from_reflected(Type::Text.to_optional(), Some("NULL")),
+            Ok(Value::Null)
+        );
+        assert_eq!(
+            Value::from_reflected(<Vec<Option<f64>>>::TYPE, Some("[1.5,null]")),
+            Ok(Value::List(vec![Value::Float(1.5), Value::Null]))
+        );
+        assert!(Value::from_reflected(Type::Bool, Some("true")).is_err());
+
+        assert_eq!(Value::Null.to_reflected(), None);
+        assert_eq!(Value::Float(2.0).to_reflected(), Some("2.0".to_string()));
+        assert_eq!(
+            Value::List(vec![Value::Text("a".to_string()), Value::Int(1), Value::Null]).to_reflected(),
+            Some(r#"["a",1,null]"#.to_string())
+        );
+        assert_eq!(
+            Value::List(vec![Value::Bool(true), Value::Bool(false)]).to_reflected(),
+            vec![true, false].to_reflected_value().into()
+        );
+        assert_eq!(
+            Value::List(vec![Value::Float(f64::NAN), Value::Float(f64::INFINITY)]).to_reflected(),
+            vec![f64::NAN, f64::INFINITY].to_reflected_value().into()
+        );
+        assert_eq!(
+            Value::Map([("a".to_string(), Value::Bool(true))].into()).to_reflected(),
+            Some(r#"{"a":true}"#.to_string())
+        );
+
+        assert_eq!(Value::from_unsigned(5), Value::Int(5));
+        assert_eq!(
+            Value::Int(300).into_integer::<u8>(),
+            Err("Integer value out of range: Int(300)".to_string())
+        );
+        assert_eq!(Value::Int(-1).into_integer::<i8>(), Ok(-1));
+        assert!(Value::Text("1".to_string()).into_integer::<i8>().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::HashMap, path::Path};
+
+use quote::ToTokens;
+use syn::{__private::Span, Error, LitStr, Result};
+use toml::{Table, Value};
+
+/// Names of `reflected::Type` variants which can be set with
+/// `#[reflected(type = "...")]` or registered as alias targets
+pub(crate) const TYPES: &[&str] = &[
+    "Float",
+    "Integer",
+    "Text",
+    "Date",
+    "Decimal",
+    "Bool",
+    "Enum",
+    "Duration",
+    "DateTime",
+    "NaiveDate",
+    "NaiveTime",
+    "Bytes",
+    "Uuid",
+    "Json",
+];
+
+/// Types of `TYPES` which exist only with a feature of `reflected`: type,
+/// feature and whether it is enabled
+const FEATURE_TYPES: &[(&str, &str, bool)] = &[
+    ("Uuid", "uuid", cfg!(feature = "uuid")),
+    ("Json", "json", cfg!(feature = "json")),
+];
+
+/// Type aliases and newtypes registered for the whole crate in its
+/// `Cargo.toml`:
+///
+/// ```toml
+/// [package.metadata.reflected.aliases]
+/// UserId = "Integer"
+/// Money = "Decimal"
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct Aliases {
+    types: HashMap<String, String>,
+}
+
+impl Aliases {
+    /// Reads aliases of the crate being compiled
+    pub(crate) fn load() -> Result<Self> {
+        let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+            return Ok(Self::default());
+        };
+
+        let path = Path::new(&dir).join("Cargo.toml");
+
+        let Ok(manifest) = std::fs::read_to_string(&path) else {
+            return Ok(Self::default());
+        };
+
+        Self::parse(&manifest)
+            .map_err(|err| Error::new(Span::call_site(), format!("{}: {err}", path.display())))
+    }
+
+    fn parse(manifest: &str) -> std::result::Result<Self, String> {
+        let manifest: Table = manifest.parse().map_err(|err| format!("Failed to parse manifest: {err}"))?;
+
+        let aliases = manifest
+            .get("package")
+            .and_then(|package| package.get("metadata"))
+            .and_then(|metadata| metadata.get("reflected"))
+            .and_then(|reflected| reflected.get("aliases"));
+
+        let Some(aliases) = aliases else {
+            return Ok(Self::default());
+        };
+
+        let Value::Table(aliases) = aliases else {
+            return Err("`package.metadata.reflected.aliases` must be a table".to_string());
+        };
+
+        let mut types = HashMap::new();
+
+        for (name, tp) in aliases {
+            let Some(tp) = tp.as_str().filter(|tp| TYPES.contains(tp)) else {
+                return Err(format!(
+                    "Invalid reflected type of alias `{name}`: {tp}. Supported: {}",
+                    supported()
+                ));
+            };
+
+            types.insert(name.clone(), tp.to_string());
+        }
+
+        Ok(Self { types })
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&String> {
+        self.types.get(name)
+    }
+}
+
+/// Value of `#[reflected(type = "...")]`
+pub(crate) fn parse_type(lit: &LitStr) -> Result<String> {
+    let value = lit.value();
+
+    if TYPES.contains(&value.as_str()) {
+        check_feature(&value, lit)?;
+        return Ok(value);
+    }
+
+    Err(Error::new_spanned(
+        lit,
+        format!("Unknown reflected type: \"{value}\". Supported: {}", supported()),
+    ))
+}
+
+/// Error spanned to `tokens` if reflected type `tp` needs a disabled feature
+pub(crate) fn check_feature(tp: &str, tokens: impl ToTokens) -> Result<()> {
+    match FEATURE_TYPES.iter().find(|(name, ..)| *name == tp) {
+        Some((_, feature, false)) => Err(Error::new_spanned(
+            tokens,
+            format!("Reflected type \"{tp}\" requires `{feature}` feature of reflected"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn supported() -> String {
+    let types: Vec<_> = TYPES.iter().map(|tp| format!("\"{tp}\"")).collect();
+    types.join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aliases::Aliases;
+
+    #[test]
+    fn parse() {
+        let aliases = Aliases::parse(
+            r#"
+            [package]
+            name = "app"
+
+            [package.metadata.reflected.aliases]
+            UserId = "Integer"
+            Money = "Decimal"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(aliases.get("UserId").unwrap(), "Integer");
+        assert_eq!(aliases.get("Money").unwrap(), "Decimal");
+        assert_eq!(aliases.get("String"), None);
+
+        assert!(Aliases::parse("[package]\nname = \"app\"").unwrap().get("UserId").is_none());
+
+        let err = Aliases::parse(
+            r#"
+            [package.metadata.reflected.aliases]
+            UserId = "Int"
+            "#,
+        )
+        .unwrap_err();
+
+        assert!(err.starts_with("Invalid reflected type of alias `UserId`"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "uuid"))]
+    fn disabled_feature() {
+        use syn::parse_quote;
+
+        use crate::aliases::parse_type;
+
+        assert_eq!(
+            parse_type(&parse_quote!("Uuid")).unwrap_err().to_string(),
+            "Reflected type \"Uuid\" requires `uuid` feature of reflected"
+        );
+        assert_eq!(parse_type(&parse_quote!("Integer")).unwrap(), "Integer");
+    }
+}
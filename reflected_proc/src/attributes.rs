@@ -0,0 +1,197 @@
+use syn::{Attribute, Error, Lit, LitStr, Meta, NestedMeta, Path, Result};
+
+use crate::{aliases, rename_rule::RenameRule};
+
+/// Struct or enum options set with `#[reflected(...)]` attribute
+#[derive(Debug, Default)]
+pub(crate) struct ContainerAttributes {
+    /// Overrides `type_name()`
+    pub(crate) rename:     Option<String>,
+    /// Applied to all field or variant names without explicit `rename`
+    pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl ContainerAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in reflected_attributes(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) => match path_name(&nv.path).as_str() {
+                    "rename" => result.rename = string_value(&nv.lit)?.value().into(),
+                    "rename_all" => result.rename_all = RenameRule::parse(string_value(&nv.lit)?)?.into(),
+                    _ => return Err(unknown_attribute(&meta, CONTAINER_ATTRIBUTES)),
+                },
+                _ => return Err(unknown_attribute(&meta, CONTAINER_ATTRIBUTES)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// External name of a field or a variant
+    pub(crate) fn external_name(&self, name: &str, rename: Option<&String>) -> String {
+        if let Some(rename) = rename {
+            return rename.clone();
+        }
+
+        match self.rename_all {
+            Some(rule) => rule.apply(name),
+            None => name.to_string(),
+        }
+    }
+}
+
+const CONTAINER_ATTRIBUTES: &str = "`rename = \"...\"`, `rename_all = \"...\"`";
+
+/// Generated code a field is excluded from with `#[reflected(skip...)]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Skip {
+    /// `skip`. All generated code
+    All,
+    /// `skip_bind`. `bind_to_sqlx_query`
+    Bind,
+    /// `skip_fields`. `fields()`
+    Fields,
+    /// `skip_random`. `RandomReflected`
+    Random,
+}
+
+/// Field options set with `#[reflected(...)]` attribute
+#[derive(Debug, Default)]
+pub(crate) struct FieldAttributes {
+    pub(crate) skip:     Vec<Skip>,
+    /// Overrides `Field::name`
+    pub(crate) rename:   Option<String>,
+    /// `chrono` format of date fields in `get_value` and `set_value`
+    pub(crate) format:   Option<LitStr>,
+    /// `reflected::BytesEncoding` of bytes fields. `Hex` or `Base64`
+    pub(crate) encoding: Option<LitStr>,
+    /// Overrides `ReflectedValue::TYPE` of text values. Values are parsed as
+    /// this type in `get`, `set` and `set_value`
+    pub(crate) tp:       Option<String>,
+    /// Extra names accepted by `try_field_by_name`
+    pub(crate) aliases:  Vec<LitStr>,
+}
+
+impl FieldAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in reflected_attributes(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) => match path_name(path).as_str() {
+                    "skip" => result.skip.push(Skip::All),
+                    "skip_bind" => result.skip.push(Skip::Bind),
+                    "skip_fields" => result.skip.push(Skip::Fields),
+                    "skip_random" => result.skip.push(Skip::Random),
+                    _ => return Err(unknown_attribute(&meta, FIELD_ATTRIBUTES)),
+                },
+                NestedMeta::Meta(Meta::NameValue(nv)) => match path_name(&nv.path).as_str() {
+                    "rename" => result.rename = string_value(&nv.lit)?.value().into(),
+                    "format" => result.format = string_value(&nv.lit)?.clone().into(),
+                    "type" => result.tp = aliases::parse_type(string_value(&nv.lit)?)?.into(),
+                    "encoding" => result.encoding = parse_encoding(string_value(&nv.lit)?)?.into(),
+                    "alias" => result.aliases.push(string_value(&nv.lit)?.clone()),
+                    _ => return Err(unknown_attribute(&meta, FIELD_ATTRIBUTES)),
+                },
+                _ => return Err(unknown_attribute(&meta, FIELD_ATTRIBUTES)),
+            }
+        }
+
+        if let (Some(format), Some(_)) = (&result.format, &result.encoding) {
+            return Err(Error::new_spanned(
+                format,
+                "`format` and `encoding` can't be used together",
+            ));
+        }
+
+        Ok(result)
+    }
+
+    pub(crate) fn skips(&self, skip: Skip) -> bool {
+        self.skip.contains(&skip)
+    }
+}
+
+/// `reflected::BytesEncoding` variant name of `#[reflected(encoding = "...")]`
+fn parse_encoding(lit: &LitStr) -> Result<LitStr> {
+    let variant = match lit.value().as_str() {
+        "hex" => "Hex",
+        "base64" => "Base64",
+        value => {
+            return Err(Error::new_spanned(
+                lit,
+                format!("Unknown bytes encoding: \"{value}\". Supported: \"hex\", \"base64\""),
+            ));
+        }
+    };
+
+    Ok(LitStr::new(variant, lit.span()))
+}
+
+const FIELD_ATTRIBUTES: &str = "`skip`, `skip_bind`, `skip_fields`, `skip_random`, `rename = \"...\"`, \
+                                `format = \"...\"`, `type = \"...\"`, `encoding = \"...\"`, `alias = \
+                                \"...\"`";
+
+/// Enum variant options set with `#[reflected(...)]` attribute
+#[derive(Debug, Default)]
+pub(crate) struct VariantAttributes {
+    /// Overrides `ReflectedEnum::variant_name`
+    pub(crate) rename: Option<String>,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+
+        for meta in reflected_attributes(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) if path_name(&nv.path) == "rename" => {
+                    result.rename = string_value(&nv.lit)?.value().into();
+                }
+                _ => return Err(unknown_attribute(&meta, VARIANT_ATTRIBUTES)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+const VARIANT_ATTRIBUTES: &str = "`rename = \"...\"`";
+
+/// Contents of all `#[reflected(...)]` attributes
+fn reflected_attributes(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut result = vec![];
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident("reflected")) {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(Error::new_spanned(
+                attr,
+                "Expected attribute in format: `#[reflected(...)]`",
+            ));
+        };
+
+        result.extend(list.nested);
+    }
+
+    Ok(result)
+}
+
+fn path_name(path: &Path) -> String {
+    path.get_ident().map(ToString::to_string).unwrap_or_default()
+}
+
+fn string_value(lit: &Lit) -> Result<&LitStr> {
+    match lit {
+        Lit::Str(lit) => Ok(lit),
+        _ => Err(Error::new_spanned(lit, "Expected string literal")),
+    }
+}
+
+fn unknown_attribute(meta: &NestedMeta, supported: &str) -> Error {
+    Error::new_spanned(
+        meta,
+        format!("Unknown reflected attribute. Supported: {supported}"),
+    )
+}
@@ -1,76 +1,69 @@
 use std::str::FromStr;
 
-use syn::{__private::Span, Ident};
+use syn::{__private::Span, Ident, LitInt, Member, Type};
 
-use crate::TokenStream2;
+use crate::{TokenStream2, attributes::FieldAttributes};
 
 #[derive(Debug)]
 pub(crate) struct Field {
-    pub(crate) name:     Ident,
-    pub(crate) tp:       Ident,
-    pub(crate) optional: bool,
+    /// `self.name` for named fields and `self.0` for tuple struct fields
+    pub(crate) member:         Member,
+    /// External name of the field. Differs from `member` if renamed
+    pub(crate) name:           String,
+    /// Last path segment of `value_ty`. `NaiveDateTime` for
+    /// `Option<chrono::NaiveDateTime>`
+    pub(crate) tp:             String,
+    /// Normalized display of `value_ty`. `chrono::NaiveDateTime`
+    pub(crate) type_name:      String,
+    /// Type of the field as declared
+    pub(crate) ty:             Type,
+    /// Type of the field without `Option`
+    pub(crate) value_ty:       Type,
+    pub(crate) optional:       bool,
+    /// `reflected::Type` set with `#[reflected(type = "...")]` or registered
+    /// as a crate alias
+    pub(crate) reflected_type: Option<String>,
+    pub(crate) attrs:          FieldAttributes,
+    /// Position in `fields()`. Fields with `skip_fields` come after all other
+    /// fields
+    pub(crate) index:          usize,
 }
 
 impl Field {
     pub(crate) fn type_as_string(&self) -> TokenStream2 {
-        TokenStream2::from_str(&format!("\"{}\"", self.tp)).unwrap()
+        TokenStream2::from_str(&format!("{:?}", self.type_name)).unwrap()
     }
 
-    pub(crate) fn name_as_string(&self) -> TokenStream2 {
-        TokenStream2::from_str(&format!("\"{}\"", self.name)).unwrap()
-    }
-
-    pub(crate) fn _id(&self) -> bool {
-        self.name == Ident::new("id", Span::call_site())
-    }
-
-    pub(crate) fn _is_foreign_id(&self) -> bool {
-        self.name.to_string().contains("_id")
-    }
-
-    pub(crate) fn custom(&self) -> bool {
-        self.field_type() == Ident::new("Custom", Span::call_site())
+    pub(crate) fn index_literal(&self) -> LitInt {
+        LitInt::new(&self.index.to_string(), Span::call_site())
     }
 
-    pub(crate) fn is_bool(&self) -> bool {
-        self.field_type() == "Bool"
-    }
-
-    pub(crate) fn is_float(&self) -> bool {
-        self.field_type() == "Float"
+    pub(crate) fn name_as_string(&self) -> TokenStream2 {
+        TokenStream2::from_str(&format!("\"{}\"", self.name)).unwrap()
     }
 
-    pub(crate) fn is_duration(&self) -> bool {
-        self.field_type() == "Duration"
+    /// Name of generated `pub const` field. `NAME` for named fields and `_0`
+    /// for tuple struct fields
+    pub(crate) fn const_name(&self) -> Ident {
+        match &self.member {
+            Member::Named(name) => Ident::new(&name.to_string().to_uppercase(), name.span()),
+            Member::Unnamed(index) => Ident::new(&format!("_{}", index.index), index.span),
+        }
     }
 
-    pub(crate) fn is_date(&self) -> bool {
-        self.field_type() == "Date"
+    /// Name of the field in generated `{Name}Fields` struct
+    pub(crate) fn struct_field_name(&self) -> Ident {
+        match &self.member {
+            Member::Named(name) => name.clone(),
+            Member::Unnamed(index) => Ident::new(&format!("_{}", index.index), index.span),
+        }
     }
 
-    pub(crate) fn _is_enum(&self) -> bool {
-        self.field_type() == "Enum"
+    pub(crate) fn id(&self) -> bool {
+        matches!(&self.member, Member::Named(name) if name == "id")
     }
 
-    pub(crate) fn field_type(&self) -> Ident {
-        let float = Ident::new("Float", Span::call_site());
-        let integer = Ident::new("Integer", Span::call_site());
-        let text = Ident::new("Text", Span::call_site());
-        let en = Ident::new("Enum", Span::call_site());
-        let date = Ident::new("Date", Span::call_site());
-        let decimal = Ident::new("Decimal", Span::call_site());
-        let bool = Ident::new("Bool", Span::call_site());
-        let duration = Ident::new("Duration", Span::call_site());
-
-        match self.tp.to_string().as_str() {
-            "f32" | "f64" => float,
-            "ID" | "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => integer,
-            "String" => text,
-            "NaiveDateTime" | "DateTime" => date,
-            "Decimal" => decimal,
-            "bool" => bool,
-            "Duration" => duration,
-            _ => en,
-        }
+    pub(crate) fn _is_foreign_id(&self) -> bool {
+        self.name.contains("_id")
     }
 }
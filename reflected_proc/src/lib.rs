@@ -1,17 +1,28 @@
 use std::str::FromStr;
 
 use proc_macro::TokenStream;
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
     __private::{Span, TokenStream2},
-    Attribute, Data, DeriveInput, Fields, FieldsNamed, GenericArgument, Ident, Meta, NestedMeta,
-    PathArguments, Type, parse_macro_input,
+    Data, DeriveInput, Error, Fields, Generics, Ident, Index, LitByteStr, LitStr, Member, Result, Type,
+    parse_macro_input, parse_quote,
+    spanned::Spanned,
 };
 
-use crate::{field::Field, reflect_enum::reflect_enum};
+use crate::{
+    aliases::Aliases,
+    attributes::{ContainerAttributes, FieldAttributes, Skip},
+    field::Field,
+    reflect_enum::reflect_enum,
+    type_info::TypeInfo,
+};
 
+mod aliases;
+mod attributes;
 mod field;
 mod reflect_enum;
+mod rename_rule;
+mod type_info;
 
 #[cfg(feature = "sqlx_bind")]
 const SQLX_BIND_ENABLED: bool = true;
@@ -20,25 +31,33 @@ const SQLX_BIND_ENABLED: bool = true;
 const SQLX_BIND_ENABLED: bool = false;
 
 /// Data must also derive `Default`
-#[proc_macro_derive(Reflected)]
+#[proc_macro_derive(Reflected, attributes(reflected))]
 pub fn reflected(stream: TokenStream) -> TokenStream {
-    let mut stream = parse_macro_input!(stream as DeriveInput);
-
-    let data = match &mut stream.data {
-        Data::Struct(data) => data,
-        Data::Enum(en) => return reflect_enum(en),
-        Data::Union(_) => panic!("Unsupported data type: {:?}", stream.data),
+    let stream = parse_macro_input!(stream as DeriveInput);
+
+    let result = match &stream.data {
+        Data::Struct(_) => reflect_struct(&stream),
+        Data::Enum(en) => reflect_enum(&stream.ident, &stream.attrs, en),
+        Data::Union(data) => Err(Error::new_spanned(
+            data.union_token,
+            "Reflected can't be derived for unions. Only structs and enums with unit variants are supported",
+        )),
     };
 
-    let Fields::Named(struct_fields) = &mut data.fields else {
-        panic!()
+    result.unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn reflect_struct(stream: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &stream.data else {
+        unreachable!("reflect_struct is only called for structs")
     };
 
-    let (rename, fields) = parse_fields(struct_fields);
+    let attrs = ContainerAttributes::parse(&stream.attrs)?;
+    let fields = parse_fields(&data.fields, &attrs, &Aliases::load()?)?;
 
     let name = stream.ident.clone();
 
-    let name_string = if let Some(rename) = rename {
+    let name_string = if let Some(rename) = &attrs.rename {
         TokenStream2::from_str(&format!("\"{rename}\""))
     } else {
         TokenStream2::from_str(&format!("\"{name}\""))
@@ -47,18 +66,60 @@ pub fn reflected(stream: TokenStream) -> TokenStream {
 
     let fields_struct_name = Ident::new(&format!("{name}Fields"), Span::call_site());
 
-    let fields_struct = fields_struct(&name, &fields);
-    let fields_const_var = fields_const_var(&name, &fields);
-    let fields_reflect = fields_reflect(&name, &fields);
-    let get_value = fields_get_value(&fields);
-    let set_value = fields_set_value(&fields);
+    let (impl_generics, ty_generics, where_clause) = stream.generics.split_for_impl();
+
+    let reflected_generics = reflected_generics(&quote!(#name #ty_generics), &stream.generics, &fields);
+    let (_, _, reflected_where_clause) = reflected_generics.split_for_impl();
+
+    let fields_struct = fields_struct(&quote!(#name #ty_generics), &fields);
+    let typed_fields = typed_fields(&quote!(#name #ty_generics), &fields);
+    let fields_const_var = fields_const_var(&name_string, &fields);
+    let reflected_impl = reflected_impl(&name_string, &fields);
+    let reflected_value_impl = reflected_value_impl(&name_string, &fields);
+
+    Ok(quote! {
+        #[derive(Debug)]
+        pub struct #fields_struct_name #impl_generics #where_clause {
+            #fields_struct
+        }
+
+        impl #impl_generics #fields_struct_name #ty_generics #reflected_where_clause {
+            #typed_fields
+        }
+
+        impl #impl_generics #name #ty_generics #reflected_where_clause {
+            #fields_const_var
+        }
+
+        impl #impl_generics ::reflected::Reflected for #name #ty_generics #reflected_where_clause {
+            #reflected_impl
+        }
+
+        impl #impl_generics ::reflected::ReflectedValue for #name #ty_generics #reflected_where_clause {
+            #reflected_value_impl
+        }
+    })
+}
+
+/// Body of `Reflected` impl
+fn reflected_impl(name_string: &TokenStream2, fields: &Vec<Field>) -> TokenStream2 {
+    let fields_reflect = fields_reflect(fields);
+    let random_fields = random_fields(fields);
+    let get_value = fields_get_value(fields);
+    let set_value = fields_set_value(fields);
+    let get = fields_get(fields);
+    let field_by_name = fields_by_name(fields);
+    let field_count = fields.iter().filter(|field| !field.attrs.skips(Skip::Fields)).count();
+    let set = fields_set(fields);
 
     let sqlx_bind_code = if SQLX_BIND_ENABLED {
-        let sqlx_bind = fields_sqlx_bind(&fields);
+        let sqlx_bind = fields_sqlx_bind(fields);
 
         quote! {
-            fn bind_to_sqlx_query<'q, O>(self, query: sqlx::query::QueryAs<'q, sqlx::Postgres, O, <sqlx::Postgres as sqlx::Database>::Arguments<'q>>,)
-                ->  sqlx::query::QueryAs<'q, sqlx::Postgres, O, <sqlx::Postgres as sqlx::Database>::Arguments<'q>> {
+            fn bind_to_sqlx_query<'q, O>(self, query: ::reflected::SqlxQuery<'q, O>) -> ::reflected::SqlxQuery<'q, O> {
+                #[allow(unused_imports)]
+                use ::reflected::{BindNative as _, BindReflected as _};
+
                 let mut query = query;
                 #sqlx_bind
                 query
@@ -68,92 +129,152 @@ pub fn reflected(stream: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    let unknown_field = |value: TokenStream2| {
+        quote! {
+            ::reflected::ReflectError::new(
+                <Self as ::reflected::Reflected>::type_name(),
+                field.name,
+                #value,
+                ::reflected::ReflectErrorKind::UnknownField,
+            )
+        }
+    };
+
+    let unknown = unknown_field(quote!(None));
+    let unknown_input = unknown_field(quote!(value));
+
     quote! {
-        #[derive(Debug)]
-        pub struct #fields_struct_name {
-            #fields_struct
+        const FIELD_COUNT: usize = #field_count;
+
+        fn type_name() -> &'static str {
+            #name_string
         }
 
-        impl #name {
-            #fields_const_var
+        fn fields() -> &'static [::reflected::Field<Self>] {
+            &[
+                #fields_reflect
+            ]
         }
 
-        impl reflected::Reflected for #name {
-            fn type_name() -> &'static str {
-                #name_string
-            }
+        #random_fields
+
+        #field_by_name
 
-            fn fields() -> &'static [reflected::Field<Self>] {
-                &[
-                    #fields_reflect
-                ]
+        fn try_get_value(&self, field: ::reflected::Field<Self>) -> Result<String, ::reflected::ReflectError> {
+            match field.index {
+                #get_value
+                _ => Err(#unknown),
             }
+        }
 
-            fn get_value(&self, field: reflected::Field<Self>) -> String {
-                use std::borrow::Borrow;
-                use reflected::ToReflectedString;
-                let field = field.borrow();
+        fn try_set_value(&mut self, field: ::reflected::Field<Self>, value: Option<&str>) -> Result<(), ::reflected::ReflectError> {
+            match field.index {
+                #set_value
+                _ => Err(#unknown_input),
+            }
+        }
 
-                if field.is_enum() {
-                    panic!("get_value method is not supported yet for enum types: {field:?}");
-                }
+        fn get(&self, field: ::reflected::Field<Self>) -> ::reflected::Value {
+            match field.index {
+                #get
+                _ => panic!("{}", #unknown),
+            }
+        }
 
-                match field.name {
-                    #get_value
-                    _ => unreachable!("Invalid field name in get_value: {}", field.name),
-                }
+        fn set(&mut self, field: ::reflected::Field<Self>, value: ::reflected::Value) -> Result<(), ::reflected::ReflectError> {
+            match field.index {
+                #set
+                _ => Err(#unknown),
             }
+        }
 
-            fn set_value(&mut self, field: reflected::Field<Self>, value: Option<&str>) {
-                use reflected::ToReflectedVal;
-                use std::borrow::Borrow;
-                let field = field.borrow();
-                match field.name {
-                    #set_value
-                    _ => unreachable!("Invalid field name in set_value: {}", field.name),
-                }
+        #sqlx_bind_code
+    }
+}
+
+/// Body of `ReflectedValue` impl. Structs are nested as JSON objects
+fn reflected_value_impl(name_string: &TokenStream2, fields: &[Field]) -> TokenStream2 {
+    let erased_fields = erased_fields(fields);
+
+    quote! {
+        const TYPE: ::reflected::Type = ::reflected::Type::Struct(&::reflected::StructType {
+            name: #name_string,
+            fields: || const { &[#erased_fields] },
+        });
+        const RANDOM: bool = true;
+
+        fn to_reflected_value(&self) -> String {
+            ::reflected::Reflected::to_reflected_json(self)
+        }
+
+        fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+            let value = value.ok_or_else(|| format!("Trying to set {} from NULL", #name_string))?;
+            ::reflected::Reflected::from_reflected_json(value)
+        }
+
+        fn to_value(&self) -> ::reflected::Value {
+            ::reflected::Value::Struct(
+                ::reflected::Reflected::fields()
+                    .iter()
+                    .map(|field| (field.name.to_string(), ::reflected::Reflected::get(self, *field)))
+                    .collect(),
+            )
+        }
+
+        fn from_value(value: ::reflected::Value) -> Result<Self, String> {
+            let ::reflected::Value::Struct(values) = value else {
+                return Err(format!("Expected Struct value for {}, got: {value:?}", #name_string));
+            };
+
+            let mut result = Self::default();
+
+            for (name, value) in values {
+                let field = <Self as ::reflected::Reflected>::fields()
+                    .iter()
+                    .find(|field| field.name == name)
+                    .ok_or_else(|| format!("Unknown field {name} of {}", #name_string))?;
+                ::reflected::Reflected::set(&mut result, *field, value).map_err(|err| err.to_string())?;
             }
 
-            #sqlx_bind_code
+            Ok(result)
         }
     }
-    .into()
 }
 
-fn fields_const_var(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
+fn fields_const_var(type_name: &TokenStream2, fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
-    let type_name_string = TokenStream2::from_str(&format!("\"{type_name}\"")).unwrap();
-
     for field in fields {
-        let name = TokenStream2::from_str(&field.name.to_string().to_uppercase()).unwrap();
-
-        let field_type = field.field_type();
+        let name = field.const_name();
+        let ty = &field.ty;
 
         let field_type_name = field.type_as_string();
         let name_string = field.name_as_string();
+        let index = field.index_literal();
 
-        let optional = field.optional;
-
-        let tp = if optional {
+        let tp = if let Some(tp) = &field.reflected_type {
+            let tp = Ident::new(tp, Span::call_site());
             quote! {
-                tp: reflected::Type::#field_type.to_optional()
+                tp: <#ty as ::reflected::ReflectedValue>::TYPE.reflected_as(::reflected::Type::#tp)
             }
         } else {
             quote! {
-                tp: reflected::Type::#field_type
+                tp: <#ty as ::reflected::ReflectedValue>::TYPE
             }
         };
 
         res = quote! {
             #res
-            pub const #name: reflected::Field<#type_name> = reflected::Field {
+            pub const #name: ::reflected::Field<Self> = ::reflected::Field {
                 name: #name_string,
                 #tp,
                 type_name: #field_type_name,
-                parent_name: #type_name_string,
-                optional: #optional,
-                _p: std::marker::PhantomData,
+                parent_name: #type_name,
+                optional: <#ty as ::reflected::ReflectedValue>::OPTIONAL,
+                integer_kind: <#ty as ::reflected::ReflectedValue>::INTEGER_KIND,
+                random: <#ty as ::reflected::ReflectedValue>::RANDOM,
+                index: #index,
+                _p: ::std::marker::PhantomData,
             };
         }
     }
@@ -161,14 +282,34 @@ fn fields_const_var(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
     res
 }
 
-fn fields_struct(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
+/// `TypedField` consts of `{Name}Fields` struct. Kept out of the reflected
+/// type so they can't collide with `Field` consts
+fn typed_fields(type_name: &TokenStream2, fields: &[Field]) -> TokenStream2 {
+    let consts = fields.iter().map(|field| {
+        let name = field.const_name();
+        let member = &field.member;
+        let ty = &field.ty;
+
+        quote! {
+            pub const #name: ::reflected::TypedField<#type_name, #ty> = ::reflected::TypedField::new(
+                <#type_name>::#name,
+                |obj| &obj.#member,
+                |obj| &mut obj.#member,
+            );
+        }
+    });
+
+    quote! { #(#consts)* }
+}
+
+fn fields_struct(type_name: &TokenStream2, fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        let name = &field.name;
+        let name = field.struct_field_name();
         res = quote! {
             #res
-            pub #name: reflected::Field<#type_name>,
+            pub #name: ::reflected::Field<#type_name>,
         }
     }
 
@@ -177,52 +318,92 @@ fn fields_struct(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
     }
 }
 
-fn fields_reflect(name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
+fn fields_reflect(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        let field_name = TokenStream2::from_str(&field.name.to_string().to_uppercase()).unwrap();
+        if field.attrs.skips(Skip::Fields) {
+            continue;
+        }
+
+        let field_name = field.const_name();
         res = quote! {
             #res
-            #name::#field_name,
+            Self::#field_name,
         }
     }
 
     res
 }
 
+fn erased_fields(fields: &[Field]) -> TokenStream2 {
+    let fields = fields
+        .iter()
+        .filter(|field| !field.attrs.skips(Skip::Fields))
+        .map(Field::const_name);
+
+    quote! {
+        #(Self::#fields.erased(),)*
+    }
+}
+
+fn random_fields(fields: &[Field]) -> TokenStream2 {
+    if !fields.iter().any(|field| field.attrs.skips(Skip::Random)) {
+        return quote!();
+    }
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.attrs.skips(Skip::Fields) && !field.attrs.skips(Skip::Random))
+        .map(Field::const_name);
+
+    quote! {
+        fn random_fields() -> &'static [::reflected::Field<Self>] {
+            &[#(Self::#fields,)*]
+        }
+    }
+}
+
 fn fields_get_value(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        if field.custom() {
-            continue;
-        }
+        let field_name = &field.member;
+        let index = field.index_literal();
 
-        let field_name = &field.name;
-        let name_string = field.name_as_string();
+        if let Some(format) = &field.attrs.format {
+            let date = quote_spanned!(format.span()=> ::reflected::ReflectedDate);
 
-        if field.is_bool() {
             if field.optional {
                 res = quote! {
                     #res
-                    #name_string => self.#field_name.map(|a| if a { "1" } else { "0" }.to_string()).unwrap_or("NULL".to_string()),
+                    #index => Ok(self.#field_name.as_ref().map_or("NULL".to_string(), |a| #date::format_reflected_date(a, #format))),
                 }
             } else {
                 res = quote! {
                     #res
-                    #name_string => if self.#field_name { "1" } else { "0" }.to_string(),
+                    #index => Ok(#date::format_reflected_date(&self.#field_name, #format)),
                 }
             }
-        } else if field.optional || field.is_float() || field.is_duration() {
-            res = quote! {
-                #res
-                #name_string => self.#field_name.to_reflected_string(),
+        } else if let Some(encoding) = &field.attrs.encoding {
+            let bytes = quote_spanned!(encoding.span()=> ::reflected::ReflectedBytes);
+            let encoding = Ident::new(&encoding.value(), encoding.span());
+
+            if field.optional {
+                res = quote! {
+                    #res
+                    #index => Ok(self.#field_name.as_ref().map_or("NULL".to_string(), |a| #bytes::encode_reflected_bytes(a, ::reflected::BytesEncoding::#encoding))),
+                }
+            } else {
+                res = quote! {
+                    #res
+                    #index => Ok(#bytes::encode_reflected_bytes(&self.#field_name, ::reflected::BytesEncoding::#encoding)),
+                }
             }
         } else {
             res = quote! {
                 #res
-                #name_string => self.#field_name.to_string(),
+                #index => Ok(::reflected::ReflectedValue::to_reflected_value(&self.#field_name)),
             }
         }
     }
@@ -230,73 +411,104 @@ fn fields_get_value(fields: &Vec<Field>) -> TokenStream2 {
     res
 }
 
+/// `ReflectError` of field `name` for `value` being set
+fn reflect_error(name: &TokenStream2, kind: TokenStream2) -> TokenStream2 {
+    quote! {
+        ::reflected::ReflectError::new(
+            <Self as ::reflected::Reflected>::type_name(),
+            #name,
+            value,
+            ::reflected::ReflectErrorKind::#kind,
+        )
+    }
+}
+
 fn fields_set_value(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        if field.custom() {
-            continue;
-        }
-
-        let field_name = &field.name;
+        let field_name = &field.member;
         let name_string = field.name_as_string();
+        let index = field.index_literal();
+        let ty = &field.ty;
+        let value_ty = &field.value_ty;
+
+        let missing = reflect_error(&name_string, quote!(MissingValue));
+        let parse = reflect_error(&name_string, quote!(Parse(err)));
+
+        if let Some(format) = &field.attrs.format {
+            let date = quote_spanned!(format.span()=> ::reflected::ReflectedDate);
 
-        if field.is_bool() {
             if field.optional {
                 res = quote! {
                     #res
-                    #name_string =>  {
-                        self.#field_name = value.map(|a| match a {
-                            "0" => false,
-                            "1" => true,
-                            _ => unreachable!("Invalid value in bool: {value:?}")
-                        })
-                    },
+                    #index => {
+                        self.#field_name = value
+                            .map(|a| <#value_ty as #date>::parse_reflected_date(a, #format))
+                            .transpose()
+                            .map_err(|err| #parse)?;
+                        Ok(())
+                    }
                 }
             } else {
                 res = quote! {
                     #res
-                    #name_string =>  {
-                        self.#field_name = match value.expect("Trying to set non optional bool with None value") {
-                            "0" => false,
-                            "1" => true,
-                            _ => unreachable!("Invalid value in bool: {value:?}")
-                        }
-                    },
+                    #index => {
+                        let Some(date) = value else {
+                            return Err(#missing);
+                        };
+                        self.#field_name = <#value_ty as #date>::parse_reflected_date(date, #format).map_err(|err| #parse)?;
+                        Ok(())
+                    }
                 }
             }
-        } else if field.is_date() {
+        } else if let Some(encoding) = &field.attrs.encoding {
+            let bytes = quote_spanned!(encoding.span()=> ::reflected::ReflectedBytes);
+            let encoding = Ident::new(&encoding.value(), encoding.span());
+
             if field.optional {
                 res = quote! {
                     #res
-                    #name_string =>  {
-                        self.#field_name = value.map(|a|
-                            sercli::DateTime::parse_from_str(&a, "%Y-%m-%d %H:%M:%S%.f").unwrap_or_else(|err| {
-                                panic!("Failed to parse date from: {}. Err: {err}", a);
-                            }).into()
-                        )
-                    },
+                    #index => {
+                        self.#field_name = value
+                            .map(|a| <#value_ty as #bytes>::decode_reflected_bytes(a, ::reflected::BytesEncoding::#encoding))
+                            .transpose()
+                            .map_err(|err| #parse)?;
+                        Ok(())
+                    }
                 }
             } else {
                 res = quote! {
                     #res
-                    #name_string => self.#field_name =
-                        sercli::DateTime::parse_from_str(&value.expect("Trying to set non optional date from None value"), "%Y-%m-%d %H:%M:%S%.f").unwrap_or_else(|err| {
-                            panic!("Failed to parse date from: {}. Err: {err}", value.expect("Should be ok. reflected data parse"));
-                        }).into(),
+                    #index => {
+                        let Some(bytes) = value else {
+                            return Err(#missing);
+                        };
+                        self.#field_name = <#value_ty as #bytes>::decode_reflected_bytes(bytes, ::reflected::BytesEncoding::#encoding).map_err(|err| #parse)?;
+                        Ok(())
+                    }
                 }
             }
-        } else if field.optional {
-            res = quote! {
-                #res
-                #name_string => self.#field_name = value.map(|a| a.to_reflected_val()
-                    .expect(&format!("Failed to convert to: {} from: {}", #name_string, a))),
-            }
         } else {
+            // Values of fields with `#[reflected(type = "...")]` must parse as
+            // their reflected type
+            let check_type = field.reflected_type.as_ref().map(|_| {
+                let const_name = field.const_name();
+                quote! {
+                    ::reflected::Value::from_reflected(Self::#const_name.tp, value).map_err(|err| #parse)?;
+                }
+            });
+
             res = quote! {
                 #res
-                #name_string => self.#field_name = value.expect("Trying to set non optional field from None").to_reflected_val()
-                .expect(&format!("Failed to convert to: {} from: {}", #name_string, value.unwrap())),
+                #index => {
+                    if value.is_none() && !<#ty as ::reflected::ReflectedValue>::OPTIONAL {
+                        return Err(#missing);
+                    }
+                    #check_type
+                    self.#field_name = <#ty as ::reflected::ReflectedValue>::from_reflected_value(value).map_err(|err| #parse)?;
+                    Ok(())
+                }
             }
         }
     }
@@ -304,17 +516,157 @@ fn fields_set_value(fields: &Vec<Field>) -> TokenStream2 {
     res
 }
 
+fn fields_by_name(fields: &[Field]) -> TokenStream2 {
+    let fields: Vec<_> = fields.iter().filter(|field| !field.attrs.skips(Skip::Fields)).collect();
+
+    let consts: Vec<_> = fields.iter().map(|field| field.const_name()).collect();
+    let names: Vec<Vec<String>> = fields
+        .iter()
+        .map(|field| {
+            std::iter::once(field.name.clone())
+                .chain(field.attrs.aliases.iter().map(LitStr::value))
+                .collect()
+        })
+        .collect();
+
+    let max_len = names.iter().flatten().map(String::len).max().unwrap_or_default();
+
+    // Names which differ only in case go to the first field
+    let mut lowercase_names: Vec<String> = vec![];
+    let mut lowercase_arms = quote!();
+
+    for (names, name_const) in names.iter().zip(&consts) {
+        let mut patterns = vec![];
+
+        for name in names {
+            let name = name.to_ascii_lowercase();
+
+            if !lowercase_names.contains(&name) {
+                patterns.push(LitByteStr::new(name.as_bytes(), Span::call_site()));
+                lowercase_names.push(name);
+            }
+        }
+
+        if !patterns.is_empty() {
+            lowercase_arms = quote! {
+                #lowercase_arms
+                #(#patterns)|* => Some(Self::#name_const),
+            };
+        }
+    }
+
+    quote! {
+        fn try_field_by_name(name: &str) -> Option<::reflected::Field<Self>> {
+            match name {
+                #(#(#names)|* => Some(Self::#consts),)*
+                _ => None,
+            }
+        }
+
+        fn try_field_by_name_ignore_case(name: &str) -> Option<::reflected::Field<Self>> {
+            let mut buffer = [0_u8; #max_len];
+            let lowercase = buffer.get_mut(..name.len())?;
+            lowercase.copy_from_slice(name.as_bytes());
+            lowercase.make_ascii_lowercase();
+
+            match &*lowercase {
+                #lowercase_arms
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Fields with `#[reflected(type = "...")]` are parsed from
+/// `to_reflected_value` as their reflected type
+fn fields_get(fields: &[Field]) -> TokenStream2 {
+    let arms = fields.iter().map(|field| {
+        let member = &field.member;
+        let index = field.index_literal();
+
+        if field.reflected_type.is_none() {
+            return quote! {
+                #index => ::reflected::ReflectedValue::to_value(&self.#member),
+            };
+        }
+
+        let const_name = field.const_name();
+        let parse = reflect_error(&field.name_as_string(), quote!(Parse(err)));
+
+        quote! {
+            #index => {
+                let value = ::reflected::ReflectedValue::to_reflected_value(&self.#member);
+                ::reflected::Value::from_reflected(Self::#const_name.tp, Some(&value)).unwrap_or_else(|err| {
+                    let value = Some(value.as_str());
+                    panic!("{}", #parse)
+                })
+            }
+        }
+    });
+
+    quote! { #(#arms)* }
+}
+
+fn fields_set(fields: &[Field]) -> TokenStream2 {
+    let arms = fields.iter().map(|field| {
+        let member = &field.member;
+        let name_string = field.name_as_string();
+        let index = field.index_literal();
+        let ty = &field.ty;
+
+        let missing = reflect_error(&name_string, quote!(MissingValue));
+        let parse = reflect_error(&name_string, quote!(Parse(err)));
+
+        let check_missing = quote! {
+            if value.is_null() && !<#ty as ::reflected::ReflectedValue>::OPTIONAL {
+                let value = None;
+                return Err(#missing);
+            }
+        };
+
+        if field.reflected_type.is_some() {
+            let const_name = field.const_name();
+
+            return quote! {
+                #index => {
+                    #check_missing
+                    let value = value.to_reflected();
+                    let value = value.as_deref();
+                    self.#member = ::reflected::Value::from_reflected(Self::#const_name.tp, value)
+                        .and_then(|_| <#ty as ::reflected::ReflectedValue>::from_reflected_value(value))
+                        .map_err(|err| #parse)?;
+                    Ok(())
+                }
+            };
+        }
+
+        quote! {
+            #index => {
+                #check_missing
+                self.#member = <#ty as ::reflected::ReflectedValue>::from_value(value.clone()).map_err(|err| {
+                    let value = value.to_reflected();
+                    let value = value.as_deref();
+                    #parse
+                })?;
+                Ok(())
+            }
+        }
+    });
+
+    quote! { #(#arms)* }
+}
+
 fn fields_sqlx_bind(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        let field_name = &field.name;
+        let field_name = &field.member;
 
-        if field_name == "id" {
+        if field.id() {
             continue;
         }
 
-        if field.custom() {
+        if field.attrs.skips(Skip::Bind) {
             continue;
         }
 
@@ -324,73 +676,388 @@ fn fields_sqlx_bind(fields: &Vec<Field>) -> TokenStream2 {
 
         res = quote! {
             #res
-            query = query.bind(self.#field_name);
+            query = ::reflected::BindValue(self.#field_name).bind(query);
         };
     }
 
     res
 }
 
-fn parse_fields(fields: &FieldsNamed) -> (Option<String>, Vec<Field>) {
-    let mut rename: Option<String> = None;
+/// Adds bounds required by generated `Reflected` impl for fields which use
+/// generic type parameters
+fn reflected_generics(type_name: &TokenStream2, generics: &Generics, fields: &[Field]) -> Generics {
+    let mut generics = generics.clone();
 
-    let fields: Vec<Field> = fields
-        .named
-        .iter()
-        .map(|field| {
-            let name = field.ident.as_ref().unwrap().clone();
-            let mut optional = false;
+    if generics.params.is_empty() {
+        return generics;
+    }
 
-            let Type::Path(path) = &field.ty else {
-                unreachable!("invalid parse_fields")
-            };
+    let type_params: Vec<Ident> = generics.type_params().map(|param| param.ident.clone()).collect();
 
-            let mut tp = path.path.segments.last().unwrap().ident.clone();
-
-            if tp == "Option" {
-                optional = true;
-                let args = &path.path.segments.first().unwrap().arguments;
-                if let PathArguments::AngleBracketed(args) = args {
-                    if let GenericArgument::Type(generic_tp) = args.args.first().unwrap() {
-                        let ident = generic_tp.to_token_stream().to_string();
-                        let ident = Ident::new(&ident, Span::call_site());
-                        tp = ident;
-                    } else {
-                        unreachable!()
-                    }
-                } else {
-                    unreachable!()
-                }
-            }
+    let where_clause = generics.make_where_clause();
 
-            let _attrs: Vec<String> = field
-                .attrs
-                .iter()
-                .map(|a| {
-                    let name = get_attribute_name(a);
-                    if name == "name" {
-                        rename = get_attribute_value(a).expect("name attribute should have value").into();
-                    }
-                    name
-                })
-                .collect();
+    where_clause.predicates.push(parse_quote!(#type_name: Default + Send + 'static));
 
-            Field { name, tp, optional }
-        })
-        .collect();
+    for field in fields {
+        if !type_params.iter().any(|param| uses_ident(&field.ty, param)) {
+            continue;
+        }
+
+        let ty = &field.ty;
+
+        where_clause.predicates.push(parse_quote!(#ty: ::reflected::ReflectedValue));
+
+        if SQLX_BIND_ENABLED && !field.attrs.skips(Skip::Bind) {
+            where_clause.predicates.push(parse_quote!(
+                #ty: for<'__q> ::sqlx::Encode<'__q, ::sqlx::Postgres> + ::sqlx::Type<::sqlx::Postgres>
+            ));
+        }
+    }
+
+    generics
+}
+
+fn uses_ident(ty: &Type, ident: &Ident) -> bool {
+    let ident = ident.to_string();
+    ty.to_token_stream()
+        .to_string()
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|token| token == ident)
+}
+
+fn parse_fields(fields: &Fields, container: &ContainerAttributes, aliases: &Aliases) -> Result<Vec<Field>> {
+    let mut result = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index {
+                index: u32::try_from(index).unwrap(),
+                span:  field.ty.span(),
+            }),
+        };
+        let attrs = FieldAttributes::parse(&field.attrs)?;
+
+        if attrs.skips(Skip::All) {
+            continue;
+        }
+
+        let name = match &member {
+            Member::Named(ident) => container.external_name(&ident.to_string(), attrs.rename.as_ref()),
+            Member::Unnamed(index) => attrs.rename.clone().unwrap_or(index.index.to_string()),
+        };
+
+        let info = TypeInfo::analyze(&field.ty)?;
+        let reflected_type = attrs.tp.clone().or_else(|| aliases.get(&info.key).cloned());
+
+        if let Some(tp) = &reflected_type {
+            aliases::check_feature(tp, &field.ty)?;
+        }
 
-    (rename, fields)
+        let field = Field {
+            member,
+            name,
+            tp: info.key,
+            type_name: info.type_name,
+            ty: field.ty.clone(),
+            value_ty: info.value_ty,
+            optional: info.optional,
+            reflected_type,
+            attrs,
+            index: 0,
+        };
+
+        result.push(field);
+    }
+
+    check_names(&result)?;
+
+    if SQLX_BIND_ENABLED {
+        check_bind(&result)?;
+    }
+
+    let (visible, skipped): (Vec<_>, Vec<_>) =
+        result.iter_mut().partition(|field| !field.attrs.skips(Skip::Fields));
+
+    for (index, field) in visible.into_iter().chain(skipped).enumerate() {
+        field.index = index;
+    }
+
+    Ok(result)
 }
 
-fn get_attribute_name(attribute: &Attribute) -> String {
-    attribute.path.segments.first().unwrap().ident.to_string()
+/// Field names and aliases must be unique
+fn check_names(fields: &[Field]) -> Result<()> {
+    let mut names: Vec<String> = vec![];
+
+    for field in fields {
+        if names.contains(&field.name) {
+            return Err(Error::new_spanned(
+                &field.member,
+                format!("Field name \"{}\" is already used", field.name),
+            ));
+        }
+
+        names.push(field.name.clone());
+    }
+
+    for alias in fields.iter().flat_map(|field| &field.attrs.aliases) {
+        if names.contains(&alias.value()) {
+            return Err(Error::new_spanned(
+                alias,
+                format!("Field name or alias \"{}\" is already used", alias.value()),
+            ));
+        }
+
+        names.push(alias.value());
+    }
+
+    Ok(())
 }
 
-fn get_attribute_value(attribute: &Attribute) -> Option<String> {
-    if let Ok(Meta::List(meta_list)) = attribute.parse_meta()
-        && let NestedMeta::Meta(Meta::Path(path)) = &meta_list.nested[0]
-    {
-        return Some(path.segments.last()?.ident.to_string());
+/// Integer types Postgres has no column type for
+const UNBOUND_INTEGERS: &[&str] = &[
+    "i128",
+    "u128",
+    "isize",
+    "NonZeroI128",
+    "NonZeroU128",
+    "NonZeroIsize",
+    "NonZeroUsize",
+];
+
+/// Fields bound by `bind_to_sqlx_query` must have Postgres type
+fn check_bind(fields: &[Field]) -> Result<()> {
+    for field in fields {
+        if field.id() || field.attrs.skips(Skip::Bind) || !UNBOUND_INTEGERS.contains(&field.tp.as_str()) {
+            continue;
+        }
+
+        return Err(Error::new_spanned(
+            &field.ty,
+            format!(
+                "`{}` can't be bound to Postgres query. Mark the field with `#[reflected(skip_bind)]`",
+                field.tp
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use syn::{DeriveInput, parse_quote};
+
+    use crate::{reflect_enum::reflect_enum, reflect_struct};
+
+    fn struct_error(input: DeriveInput) -> String {
+        reflect_struct(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn unsupported_field_type() {
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    name: &'static str,
+                }
+            })
+            .starts_with("Reference fields are not supported")
+        );
+
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    point: (i32, i32),
+                }
+            })
+            .starts_with("Unsupported field type")
+        );
+
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    name: Option<&'static str>,
+                }
+            })
+            .starts_with("Reference fields are not supported")
+        );
+    }
+
+    #[test]
+    fn bad_attribute() {
+        assert_eq!(
+            struct_error(parse_quote! {
+                #[reflected = "users"]
+                struct Data {
+                    id: i64,
+                }
+            }),
+            "Expected attribute in format: `#[reflected(...)]`"
+        );
+
+        assert_eq!(
+            struct_error(parse_quote! {
+                #[reflected(rename = 5)]
+                struct Data {
+                    id: i64,
+                }
+            }),
+            "Expected string literal"
+        );
+
+        assert!(
+            struct_error(parse_quote! {
+                #[reflected(rename_all = "Title Case")]
+                struct Data {
+                    id: i64,
+                }
+            })
+            .starts_with("Unknown rename_all rule")
+        );
+    }
+
+    #[test]
+    fn bad_encoding() {
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    #[reflected(encoding = "base32")]
+                    hash: Vec<u8>,
+                }
+            })
+            .starts_with("Unknown bytes encoding: \"base32\"")
+        );
+
+        assert_eq!(
+            struct_error(parse_quote! {
+                struct Data {
+                    #[reflected(encoding = "hex", format = "%Y")]
+                    hash: Vec<u8>,
+                }
+            }),
+            "`format` and `encoding` can't be used together"
+        );
+    }
+
+    #[test]
+    fn unknown_type_override() {
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    #[reflected(type = "Int")]
+                    id: UserId,
+                }
+            })
+            .starts_with("Unknown reflected type: \"Int\"")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx_bind")]
+    fn unbound_integer() {
+        assert_eq!(
+            struct_error(parse_quote! {
+                struct Data {
+                    total: Option<u128>,
+                }
+            }),
+            "`u128` can't be bound to Postgres query. Mark the field with `#[reflected(skip_bind)]`"
+        );
+
+        assert!(
+            reflect_struct(&parse_quote! {
+                struct Data {
+                    #[reflected(skip_bind)]
+                    total: u128,
+                }
+            })
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn duplicate_name() {
+        assert_eq!(
+            struct_error(parse_quote! {
+                struct Data {
+                    a: i32,
+                    #[reflected(rename = "a")]
+                    b: i32,
+                }
+            }),
+            "Field name \"a\" is already used"
+        );
+
+        assert_eq!(
+            struct_error(parse_quote! {
+                #[reflected(rename_all = "camelCase")]
+                struct Data {
+                    first_name: String,
+                    #[reflected(rename = "firstName")]
+                    name: String,
+                }
+            }),
+            "Field name \"firstName\" is already used"
+        );
+    }
+
+    #[test]
+    fn duplicate_alias() {
+        assert_eq!(
+            struct_error(parse_quote! {
+                struct Data {
+                    name: String,
+                    #[reflected(alias = "name")]
+                    title: String,
+                }
+            }),
+            "Field name or alias \"name\" is already used"
+        );
+
+        assert_eq!(
+            struct_error(parse_quote! {
+                struct Data {
+                    #[reflected(alias = "label")]
+                    name: String,
+                    #[reflected(alias = "label")]
+                    title: String,
+                }
+            }),
+            "Field name or alias \"label\" is already used"
+        );
+    }
+
+    #[test]
+    fn unknown_reflected_attribute() {
+        assert!(
+            struct_error(parse_quote! {
+                struct Data {
+                    #[reflected(skipp)]
+                    id: i64,
+                }
+            })
+            .starts_with("Unknown reflected attribute")
+        );
+    }
+
+    #[test]
+    fn enum_with_data() {
+        let input: DeriveInput = parse_quote! {
+            enum Shape {
+                Dot,
+                Circle(f64),
+            }
+        };
+
+        let syn::Data::Enum(en) = &input.data else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            reflect_enum(&input.ident, &input.attrs, en).unwrap_err().to_string(),
+            "Reflected enums can only have unit variants"
+        );
     }
-    None
 }
@@ -1,8 +1,101 @@
-use proc_macro::TokenStream;
-use syn::DataEnum;
+use std::str::FromStr;
 
-pub fn reflect_enum(en: &mut DataEnum) -> TokenStream {
-    dbg!(en);
+use quote::quote;
+use syn::{__private::TokenStream2, Attribute, DataEnum, Error, Fields, Ident, Result};
 
-    unimplemented!("Support of enum reflection is not implemented yet")
+use crate::attributes::{ContainerAttributes, VariantAttributes};
+
+pub fn reflect_enum(name: &Ident, attrs: &[Attribute], en: &DataEnum) -> Result<TokenStream2> {
+    let attrs = ContainerAttributes::parse(attrs)?;
+
+    if let Some(rename) = &attrs.rename {
+        return Err(Error::new_spanned(
+            name,
+            format!("`rename = \"{rename}\"` is not supported for enums. Use it on variants"),
+        ));
+    }
+
+    if let Some(variant) = en.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(Error::new_spanned(
+            &variant.fields,
+            "Reflected enums can only have unit variants",
+        ));
+    }
+
+    let variants: Vec<&Ident> = en.variants.iter().map(|v| &v.ident).collect();
+    let mut names: Vec<TokenStream2> = vec![];
+
+    for variant in &en.variants {
+        let rename = VariantAttributes::parse(&variant.attrs)?.rename;
+        let name = attrs.external_name(&variant.ident.to_string(), rename.as_ref());
+        names.push(TokenStream2::from_str(&format!("\"{name}\"")).unwrap());
+    }
+
+    let name_string = TokenStream2::from_str(&format!("\"{name}\"")).unwrap();
+
+    Ok(quote! {
+        impl ::reflected::ReflectedEnum for #name {
+            fn variants() -> &'static [Self] {
+                &[#(Self::#variants,)*]
+            }
+
+            fn variant_names() -> &'static [&'static str] {
+                &[#(#names,)*]
+            }
+
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+
+            fn from_variant_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#names => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+
+            fn discriminant(&self) -> isize {
+                match *self {
+                    #(Self::#variants => Self::#variants as isize,)*
+                }
+            }
+
+            fn from_discriminant(discriminant: isize) -> Option<Self> {
+                #(
+                    if discriminant == Self::#variants as isize {
+                        return Some(Self::#variants);
+                    }
+                )*
+                None
+            }
+        }
+
+        impl ::reflected::ReflectedValue for #name {
+            const TYPE: ::reflected::Type = ::reflected::Type::Enum;
+
+            fn to_reflected_value(&self) -> String {
+                ::reflected::ReflectedEnum::variant_name(self).to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                let value = value.ok_or_else(|| format!("Trying to set {} from NULL", #name_string))?;
+                ::reflected::ToReflectedVal::<#name>::to_reflected_val(&value)
+            }
+        }
+
+        impl ::reflected::ToReflectedString for #name {
+            fn to_reflected_string(&self) -> String {
+                ::reflected::ReflectedEnum::variant_name(self).to_string()
+            }
+        }
+
+        impl ::reflected::ToReflectedVal<#name> for &str {
+            fn to_reflected_val(&self) -> Result<#name, String> {
+                <#name as ::reflected::ReflectedEnum>::from_variant_name(self)
+                    .ok_or_else(|| format!("Invalid variant of {}: {}", #name_string, self))
+            }
+        }
+    })
 }
@@ -0,0 +1,122 @@
+use syn::{Error, LitStr, Result};
+
+/// Case conversion applied by `#[reflected(rename_all = "...")]`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl RenameRule {
+    const ALL: &'static [(&'static str, Self)] = &[
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("camelCase", Self::Camel),
+        ("PascalCase", Self::Pascal),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+    ];
+
+    pub(crate) fn parse(lit: &LitStr) -> Result<Self> {
+        let value = lit.value();
+
+        Self::ALL
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let supported: Vec<_> = Self::ALL.iter().map(|(name, _)| format!("\"{name}\"")).collect();
+                Error::new_spanned(
+                    lit,
+                    format!(
+                        "Unknown rename_all rule: \"{value}\". Supported: {}",
+                        supported.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Works with both `snake_case` field names and `PascalCase` variant names
+    pub(crate) fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Camel => {
+                let mut result = String::new();
+                for (i, word) in words.iter().enumerate() {
+                    if i == 0 {
+                        result.push_str(word);
+                    } else {
+                        result.push_str(&capitalize(word));
+                    }
+                }
+                result
+            }
+            Self::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Self::Snake => words.join("_"),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::Kebab => words.join("-"),
+        }
+    }
+}
+
+/// Lowercase words of `snake_case` or `Pascal` identifier
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+
+    for ch in name.chars() {
+        if ch == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if ch.is_uppercase() && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        word.extend(ch.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rename_rule::RenameRule;
+
+    #[test]
+    fn apply() {
+        assert_eq!(RenameRule::Camel.apply("user_email"), "userEmail");
+        assert_eq!(RenameRule::Pascal.apply("user_email"), "UserEmail");
+        assert_eq!(RenameRule::Snake.apply("UserEmail"), "user_email");
+        assert_eq!(RenameRule::ScreamingSnake.apply("user_email"), "USER_EMAIL");
+        assert_eq!(RenameRule::ScreamingSnake.apply("UserEmail"), "USER_EMAIL");
+        assert_eq!(RenameRule::Kebab.apply("user_email"), "user-email");
+        assert_eq!(RenameRule::Lower.apply("UserEmail"), "useremail");
+        assert_eq!(RenameRule::Upper.apply("user_email"), "USER_EMAIL");
+        assert_eq!(RenameRule::Camel.apply("id"), "id");
+    }
+}
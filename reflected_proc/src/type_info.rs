@@ -0,0 +1,241 @@
+use std::fmt::Write;
+
+use quote::ToTokens;
+use syn::{Error, GenericArgument, Path, PathArguments, Result, Type, TypePath};
+
+/// What the derive macro knows about a field type
+#[derive(Debug)]
+pub(crate) struct TypeInfo {
+    /// Field type without `Option`
+    pub(crate) value_ty:  Type,
+    pub(crate) optional:  bool,
+    /// Last path segment of `value_ty`. Empty for arrays
+    pub(crate) key:       String,
+    /// Normalized display of `value_ty`. `chrono::NaiveDateTime`, `Vec<u8>`
+    pub(crate) type_name: String,
+}
+
+impl TypeInfo {
+    pub(crate) fn analyze(ty: &Type) -> Result<Self> {
+        let ty = strip_parens(ty);
+
+        let (value_ty, optional) = match option_argument(ty)? {
+            Some(inner) => (strip_parens(inner), true),
+            None => (ty, false),
+        };
+
+        check_supported(value_ty)?;
+
+        let key = match value_ty {
+            Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+            _ => String::new(),
+        };
+
+        Ok(Self {
+            value_ty: value_ty.clone(),
+            optional,
+            key,
+            type_name: display(value_ty),
+        })
+    }
+}
+
+/// Removes parenthesis and invisible groups coming from `macro_rules`
+fn strip_parens(ty: &Type) -> &Type {
+    match ty {
+        Type::Paren(paren) => strip_parens(&paren.elem),
+        Type::Group(group) => strip_parens(&group.elem),
+        _ => ty,
+    }
+}
+
+/// `T` if type is `Option<T>`, `std::option::Option<T>` or
+/// `core::option::Option<T>`
+fn option_argument(ty: &Type) -> Result<Option<&Type>> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return Ok(None);
+    };
+
+    if !is_option_path(path) {
+        return Ok(None);
+    }
+
+    let segment = path.segments.last().unwrap();
+
+    if let PathArguments::AngleBracketed(args) = &segment.arguments
+        && args.args.len() == 1
+        && let Some(GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Ok(Some(inner));
+    }
+
+    Err(Error::new_spanned(
+        ty,
+        "Option field must have a type argument: `Option<T>`",
+    ))
+}
+
+fn is_option_path(path: &Path) -> bool {
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+
+    match segments.as_slice() {
+        [option] => option == "Option" && path.leading_colon.is_none(),
+        [krate, module, option] => {
+            (krate == "std" || krate == "core") && module == "option" && option == "Option"
+        }
+        _ => false,
+    }
+}
+
+fn check_supported(ty: &Type) -> Result<()> {
+    match ty {
+        Type::Path(TypePath { qself: None, .. }) => Ok(()),
+        Type::Array(array) => check_supported(&array.elem),
+        Type::Reference(_) => Err(Error::new_spanned(
+            ty,
+            "Reference fields are not supported. Use an owned type like `String` or exclude the field with \
+             `#[reflected(skip)]`",
+        )),
+        _ => Err(Error::new_spanned(
+            ty,
+            "Unsupported field type. Reflected supports named types like `String`, `i64`, \
+             `chrono::NaiveDateTime`, `Option<T>` or arrays like `[u8; 32]`",
+        )),
+    }
+}
+
+fn display(ty: &Type) -> String {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => display_path(path),
+        Type::Array(array) => format!("[{}; {}]", display(&array.elem), array.len.to_token_stream()),
+        Type::Slice(slice) => format!("[{}]", display(&slice.elem)),
+        Type::Tuple(tuple) => {
+            let elems: Vec<String> = tuple.elems.iter().map(display).collect();
+            if elems.len() == 1 {
+                format!("({},)", elems[0])
+            } else {
+                format!("({})", elems.join(", "))
+            }
+        }
+        Type::Reference(reference) => {
+            let lifetime = reference.lifetime.as_ref().map(|l| format!("{l} ")).unwrap_or_default();
+            let mutability = if reference.mutability.is_some() {
+                "mut "
+            } else {
+                ""
+            };
+            format!("&{lifetime}{mutability}{}", display(&reference.elem))
+        }
+        Type::Paren(paren) => display(&paren.elem),
+        Type::Group(group) => display(&group.elem),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    let mut result = String::new();
+
+    if path.leading_colon.is_some() {
+        result.push_str("::");
+    }
+
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i > 0 {
+            result.push_str("::");
+        }
+
+        result.push_str(&segment.ident.to_string());
+
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            let args: Vec<String> = args
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    GenericArgument::Type(ty) => display(ty),
+                    arg => arg.to_token_stream().to_string(),
+                })
+                .collect();
+
+            write!(result, "<{}>", args.join(", ")).unwrap();
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use syn::{Type, parse_quote};
+
+    use crate::type_info::{TypeInfo, display};
+
+    fn analyze(ty: Type) -> TypeInfo {
+        TypeInfo::analyze(&ty).unwrap()
+    }
+
+    #[test]
+    fn paths() {
+        let info = analyze(parse_quote!(chrono::NaiveDateTime));
+        assert!(!info.optional);
+        assert_eq!(info.key, "NaiveDateTime");
+        assert_eq!(info.type_name, "chrono::NaiveDateTime");
+
+        let info = analyze(parse_quote!(::rust_decimal::Decimal));
+        assert_eq!(info.key, "Decimal");
+        assert_eq!(info.type_name, "::rust_decimal::Decimal");
+    }
+
+    #[test]
+    fn options() {
+        let info = analyze(parse_quote!(Option<chrono::NaiveDateTime>));
+        assert!(info.optional);
+        assert_eq!(info.key, "NaiveDateTime");
+        assert_eq!(info.type_name, "chrono::NaiveDateTime");
+
+        let info = analyze(parse_quote!(std::option::Option<Vec<u8>>));
+        assert!(info.optional);
+        assert_eq!(info.key, "Vec");
+        assert_eq!(info.type_name, "Vec<u8>");
+
+        let info = analyze(parse_quote!(::core::option::Option<(String)>));
+        assert!(info.optional);
+        assert_eq!(info.key, "String");
+
+        let info = analyze(parse_quote!(my::Option<i32>));
+        assert!(!info.optional);
+    }
+
+    #[test]
+    fn nested() {
+        let info = analyze(parse_quote!(Option<HashMap<String, Vec<Option<i64>>>>));
+        assert!(info.optional);
+        assert_eq!(info.key, "HashMap");
+        assert_eq!(info.type_name, "HashMap<String, Vec<Option<i64>>>");
+
+        let info = analyze(parse_quote!(Option<[u8; 32]>));
+        assert!(info.optional);
+        assert_eq!(info.key, "");
+        assert_eq!(info.type_name, "[u8; 32]");
+
+        assert_eq!(display(&parse_quote!((f64, Vec<String>))), "(f64, Vec<String>)");
+        assert_eq!(display(&parse_quote!(&'a mut [u8])), "&'a mut [u8]");
+    }
+
+    #[test]
+    fn unsupported() {
+        let err = TypeInfo::analyze(&parse_quote!(&'static str)).unwrap_err();
+        assert!(err.to_string().starts_with("Reference fields are not supported"));
+
+        let err = TypeInfo::analyze(&parse_quote!(Option<&'static str>)).unwrap_err();
+        assert!(err.to_string().starts_with("Reference fields are not supported"));
+
+        let err = TypeInfo::analyze(&parse_quote!(fn() -> i32)).unwrap_err();
+        assert!(err.to_string().starts_with("Unsupported field type"));
+
+        let err = TypeInfo::analyze(&parse_quote!(Option<(u8, u8)>)).unwrap_err();
+        assert!(err.to_string().starts_with("Unsupported field type"));
+
+        let err = TypeInfo::analyze(&parse_quote!([&'static str; 2])).unwrap_err();
+        assert!(err.to_string().starts_with("Reference fields are not supported"));
+    }
+}
@@ -1,16 +1,11 @@
-use chrono::Duration;
-use reflected::{Reflected, ToReflectedVal};
+use chrono::{Duration, NaiveDateTime};
+use reflected::Reflected;
 use rust_decimal::Decimal;
 use sqlx::Type;
 
-mod sercli {
-    pub type Decimal = rust_decimal::Decimal;
-    pub type DateTime = chrono::NaiveDateTime;
-}
-
-type DateTime = sercli::DateTime;
+type DateTime = NaiveDateTime;
 
-#[derive(strum::Display, strum::EnumString, Type, Copy, Clone, Default, PartialEq, Debug)]
+#[derive(Reflected, Type, Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
 #[sqlx(type_name = "user_role", rename_all = "lowercase")]
 enum SomeEnum {
     #[default]
@@ -18,24 +13,17 @@ enum SomeEnum {
     B,
 }
 
-impl ToReflectedVal<SomeEnum> for &str {
-    fn to_reflected_val(&self) -> Result<SomeEnum, String> {
-        use std::str::FromStr;
-        Ok(SomeEnum::from_str(self).unwrap())
-    }
-}
-
 #[derive(Reflected, Clone, Default, PartialEq, Debug)]
 pub struct User {
     id:    usize,
     name:  String,
     email: String,
 
-    birthday:             sercli::DateTime,
+    birthday:             DateTime,
     age:                  usize,
     custom_id:            usize,
     cash:                 Decimal,
-    sercli_cash:          sercli::Decimal,
+    sercli_cash:          rust_decimal::Decimal,
     is_poros:             bool,
     height:               f64,
     dogs_count:           i16,
@@ -54,10 +42,26 @@ mod test {
     use std::str::FromStr;
 
     use chrono::{Duration, NaiveDateTime, TimeDelta, Utc};
-    use reflected::{Reflected, ReflectedEq};
+    use reflected::{
+        RandomReflected, Reflected, ReflectedEnum, ReflectedEq, ToReflectedString, ToReflectedVal,
+    };
     use rust_decimal::Decimal;
 
-    use crate::{User, sercli};
+    use crate::{SomeEnum, User};
+
+    /// Number of values bound by `bind_to_sqlx_query`
+    fn bound_arguments<T: Reflected>(value: T) -> sqlx::postgres::PgArguments {
+        use sqlx::Execute;
+
+        let mut query = value.bind_to_sqlx_query(sqlx::query_as::<_, (i64,)>(""));
+        query.take_arguments().unwrap().unwrap_or_default()
+    }
+
+    fn bound_count<T: Reflected>(value: T) -> usize {
+        use sqlx::Arguments;
+
+        bound_arguments(value).len()
+    }
 
     #[test]
     fn convert_date() {
@@ -85,6 +89,7 @@ mod test {
         assert!(User::HEIGHT.is_float());
         assert!(User::DOGS_COUNT.is_integer());
         assert!(User::SPENT_EATING_HOTDOGS.is_duration());
+        assert!(User::ENUM_FIELD.is_enum());
 
         assert!(User::STR_OPT.is_optional());
         assert!(User::STR_OPT.is_text());
@@ -145,12 +150,13 @@ mod test {
 
         assert_eq!(user.get_value(User::NAME), "peter".to_string());
         assert_eq!(user.get_value(User::AGE), "15".to_string());
-        assert_eq!(user.get_value(User::BIRTHDAY), birthday.to_string());
+        assert_eq!(user.get_value(User::BIRTHDAY), birthday.to_reflected_string());
         assert_eq!(user.get_value(User::CASH), "100.25".to_string());
         assert_eq!(user.get_value(User::IS_POROS), "0".to_string());
         assert_eq!(user.get_value(User::HEIGHT), "6.45".to_string());
         assert_eq!(user.get_value(User::DOGS_COUNT), "5".to_string());
         assert_eq!(user.get_value(User::SPENT_EATING_HOTDOGS), "200".to_string());
+        assert_eq!(user.get_value(User::ENUM_FIELD), "A".to_string());
 
         assert_eq!(user.get_value(User::STR_OPT), "NULL".to_string());
         assert_eq!(user.get_value(User::USIZE_OPT), "NULL".to_string());
@@ -168,7 +174,10 @@ mod test {
         assert_eq!(user.get_value(User::USIZE_OPT), "222".to_string());
         assert_eq!(user.get_value(User::BOOL_OPT), "0".to_string());
         assert_eq!(user.get_value(User::DECIMAL_OPT), "100.25".to_string());
-        assert_eq!(user.get_value(User::DEATH_DAY), birthday.to_string());
+        assert_eq!(user.get_value(User::DEATH_DAY), birthday.to_reflected_string());
+
+        // `id` and `usize` fields are not bound
+        assert_eq!(bound_count(user), 14);
     }
 
     #[test]
@@ -205,16 +214,18 @@ mod test {
         user.set_value(User::IS_POROS, "1".into());
         user.set_value(User::HEIGHT, "5.467".into());
         user.set_value(User::DOGS_COUNT, "17".into());
+        user.set_value(User::ENUM_FIELD, "B".into());
 
         assert_eq!(user.get_value(User::NAME), "parker".to_string());
         assert_eq!(user.get_value(User::AGE), "19".to_string());
-        assert_eq!(user.get_value(User::BIRTHDAY), new_bd.to_string());
+        assert_eq!(user.get_value(User::BIRTHDAY), new_bd.to_reflected_string());
         assert_eq!(user.get_value(User::CASH), "100.71".to_string());
         assert_eq!(user.get_value(User::SERCLI_CASH), "33.23".to_string());
         assert_eq!(user.get_value(User::SPENT_EATING_HOTDOGS), "555".to_string());
         assert_eq!(user.get_value(User::IS_POROS), "1".to_string());
         assert_eq!(user.get_value(User::HEIGHT), "5.467".to_string());
         assert_eq!(user.get_value(User::DOGS_COUNT), "17".to_string());
+        assert_eq!(user.get_value(User::ENUM_FIELD), "B".to_string());
 
         user.set_value(User::STR_OPT, "sokol".into());
         user.set_value(User::USIZE_OPT, "555".into());
@@ -226,7 +237,7 @@ mod test {
         assert_eq!(user.get_value(User::USIZE_OPT), "555".to_string());
         assert_eq!(user.get_value(User::BOOL_OPT), "1".to_string());
         assert_eq!(user.get_value(User::DECIMAL_OPT), "100.71".to_string());
-        assert_eq!(user.get_value(User::DEATH_DAY), new_bd.to_string());
+        assert_eq!(user.get_value(User::DEATH_DAY), new_bd.to_reflected_string());
 
         user.set_value(User::STR_OPT, None);
         user.set_value(User::USIZE_OPT, None);
@@ -254,7 +265,7 @@ mod test {
                 is_poros:             true,
                 height:               5.467,
                 dogs_count:           17,
-                enum_field:           Default::default(),
+                enum_field:           SomeEnum::B,
                 spent_eating_hotdogs: Duration::new(555, 0).unwrap(),
                 str_opt:              None,
                 usize_opt:            None,
@@ -272,7 +283,7 @@ mod test {
             id:   usize,
             name: String,
 
-            birthday:  sercli::DateTime,
+            birthday:  NaiveDateTime,
             age:       usize,
             custom_id: usize,
             cash:      Decimal,
@@ -313,6 +324,1535 @@ mod test {
         assert_eq!(data.get_value(Data::FLOAT64), "0.438297489");
     }
 
+    #[test]
+    fn generic_struct() {
+        #[derive(Default, Reflected)]
+        struct Page<T: Default + Send + 'static>
+        where T: Copy {
+            page: usize,
+            item: T,
+        }
+
+        let mut page = Page {
+            page: 2,
+            item: SomeEnum::B,
+        };
+
+        assert_eq!(Page::<SomeEnum>::fields().len(), 2);
+        assert!(Page::<SomeEnum>::ITEM.is_enum());
+
+        assert_eq!(page.get_value(Page::PAGE), "2");
+        assert_eq!(page.get_value(Page::ITEM), "B");
+
+        page.set_value(Page::PAGE, "3".into());
+        page.set_value(Page::ITEM, "A".into());
+
+        assert_eq!(page.page, 3);
+        assert_eq!(page.item, SomeEnum::A);
+    }
+
+    #[test]
+    fn tuple_struct() {
+        #[derive(Default, Reflected)]
+        struct Point(f64, f64);
+
+        #[derive(Default, Reflected)]
+        struct Money(Decimal);
+
+        assert_eq!(Point::fields(), &[Point::_0, Point::_1]);
+        assert_eq!(Point::_0.name, "0");
+        assert_eq!(Point::_1.name, "1");
+        assert!(Point::_1.is_float());
+        assert!(Money::_0.is_decimal());
+
+        let mut point = Point(1.5, 2.0);
+
+        assert_eq!(point.get_value(Point::_0), "1.5");
+        assert_eq!(point.value_by_name("1"), "2.0");
+
+        point.set_value(Point::_1, "4.25".into());
+
+        assert_eq!(point.1, 4.25);
+
+        let mut money = Money::default();
+        money.set_value(Money::_0, "10.50".into());
+
+        assert_eq!(money.get_value(Money::_0), "10.50");
+    }
+
+    #[test]
+    fn unit_struct() {
+        #[derive(Default, Reflected)]
+        struct Marker;
+
+        assert!(Marker::fields().is_empty());
+        assert_eq!(Marker::type_name(), "Marker");
+    }
+
+    #[test]
+    fn skip() {
+        #[derive(Default)]
+        struct Cache {
+            _hits: usize,
+        }
+
+        #[derive(Default, Reflected)]
+        struct Data {
+            id:        usize,
+            #[reflected(skip)]
+            _cache:    Cache,
+            #[reflected(skip)]
+            _label:    &'static str,
+            #[reflected(skip_fields)]
+            hidden:    String,
+            #[reflected(skip_random)]
+            fixed:     String,
+            #[reflected(skip_bind)]
+            not_bound: String,
+        }
+
+        assert_eq!(Data::fields(), &[Data::ID, Data::FIXED, Data::NOT_BOUND]);
+        assert_eq!(Data::random_fields(), &[Data::ID, Data::NOT_BOUND]);
+
+        let mut data = Data::random();
+
+        assert_eq!(data.fixed, "");
+        assert!(!data.not_bound.is_empty());
+
+        data.set_value(Data::HIDDEN, "secret".into());
+
+        assert_eq!(data.get_value(Data::HIDDEN), "secret");
+        assert_eq!(bound_count(data), 2);
+    }
+
+    #[test]
+    fn rename() {
+        #[derive(Default, Reflected)]
+        #[reflected(rename = "users", rename_all = "camelCase")]
+        struct User {
+            id:         usize,
+            first_name: String,
+            #[reflected(rename = "e_mail")]
+            email:      String,
+        }
+
+        #[derive(Default, Reflected)]
+        #[reflected(rename_all = "SCREAMING_SNAKE_CASE")]
+        struct Screaming {
+            last_name: String,
+        }
+
+        #[derive(Reflected, Copy, Clone, PartialEq, Debug)]
+        #[reflected(rename_all = "snake_case")]
+        enum Role {
+            SuperUser,
+            #[reflected(rename = "guest")]
+            Anonymous,
+        }
+
+        assert_eq!(User::type_name(), "users");
+        assert_eq!(User::ID.name, "id");
+        assert_eq!(User::FIRST_NAME.name, "firstName");
+        assert_eq!(User::EMAIL.name, "e_mail");
+        assert_eq!(User::EMAIL.parent_name, "users");
+        assert_eq!(Screaming::LAST_NAME.name, "LAST_NAME");
+
+        let mut user = User::default();
+
+        user.set_value(User::FIRST_NAME, "Peter".into());
+        user.set_value(User::field_by_name("e_mail"), "peter@gmail.com".into());
+
+        assert_eq!(user.first_name, "Peter");
+        assert_eq!(user.value_by_name("firstName"), "Peter");
+        assert_eq!(user.get_value(User::EMAIL), "peter@gmail.com");
+
+        assert_eq!(Role::variant_names(), &["super_user", "guest"]);
+        assert_eq!(Role::from_variant_name("guest"), Some(Role::Anonymous));
+    }
+
+    #[test]
+    fn typed_values() {
+        use reflected::{ReflectErrorKind, Value};
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Shape {
+            name:    String,
+            #[reflected(format = "%d.%m.%Y %H:%M")]
+            created: NaiveDateTime,
+            center:  Point,
+            tags:    Vec<String>,
+        }
+
+        let mut user = User {
+            height: 0.1 + 0.2,
+            str_opt: Some("NULL".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(user.get(User::HEIGHT), Value::Float(0.1 + 0.2));
+        assert_eq!(user.get(User::STR_OPT), Value::Text("NULL".to_string()));
+        assert_eq!(user.get(User::USIZE_OPT), Value::Null);
+        assert_eq!(user.get(User::ENUM_FIELD), Value::Enum("A".to_string()));
+        assert_eq!(user.get(User::IS_POROS), Value::Bool(false));
+        assert_eq!(
+            user.get(User::SPENT_EATING_HOTDOGS),
+            Value::Duration(Duration::zero())
+        );
+
+        user.set(User::AGE, Value::Int(30)).unwrap();
+        user.set(User::HEIGHT, Value::Float(1.0 / 3.0)).unwrap();
+        user.set(User::STR_OPT, Value::Null).unwrap();
+        user.set(User::ENUM_FIELD, Value::Enum("B".to_string())).unwrap();
+        user.set(User::CASH, Value::Decimal(Decimal::new(1005, 1))).unwrap();
+
+        assert_eq!(user.age, 30);
+        assert_eq!(user.height, 1.0 / 3.0);
+        assert_eq!(user.str_opt, None);
+        assert_eq!(user.enum_field, SomeEnum::B);
+        assert_eq!(user.cash, Decimal::new(1005, 1));
+
+        assert_eq!(
+            user.set(User::AGE, Value::Int(-1)).unwrap_err().kind,
+            ReflectErrorKind::Parse("Integer value out of range: Int(-1)".to_string())
+        );
+        assert_eq!(
+            user.set(User::NAME, Value::Null).unwrap_err().kind,
+            ReflectErrorKind::MissingValue
+        );
+        assert!(user.set(User::IS_POROS, Value::Text("1".to_string())).is_err());
+        assert!(user.set(User::ENUM_FIELD, Value::Enum("C".to_string())).is_err());
+        assert_eq!(user.age, 30);
+
+        let mut shape = Shape::default();
+
+        shape
+            .set(
+                Shape::CREATED,
+                Value::Date(NaiveDateTime::from_str("2024-12-24T18:30:00").unwrap()),
+            )
+            .unwrap();
+        shape
+            .set(
+                Shape::CENTER,
+                Value::Struct(vec![("x".to_string(), Value::Float(1.5))]),
+            )
+            .unwrap();
+        shape.set(Shape::TAGS, Value::List(vec![Value::Text("a".to_string())])).unwrap();
+
+        assert_eq!(shape.get_value(Shape::CREATED), "24.12.2024 18:30");
+        assert_eq!(shape.center, Point { x: 1.5, y: 0.0 });
+        assert_eq!(shape.tags, ["a"]);
+
+        assert_eq!(
+            shape.get(Shape::CENTER),
+            Value::Struct(vec![
+                ("x".to_string(), Value::Float(1.5)),
+                ("y".to_string(), Value::Float(0.0)),
+            ])
+        );
+        assert_eq!(
+            shape.get(Shape::TAGS),
+            Value::List(vec![Value::Text("a".to_string())])
+        );
+        assert_eq!(
+            shape.get(Shape::TAGS).to_reflected(),
+            Some(shape.get_value(Shape::TAGS))
+        );
+    }
+
+    #[test]
+    fn fallible_access() {
+        use reflected::{ReflectError, ReflectErrorKind};
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Address {
+            city: String,
+        }
+
+        #[derive(Default, Reflected)]
+        struct Event {
+            #[reflected(format = "%d.%m.%Y")]
+            day:     chrono::NaiveDate,
+            address: Address,
+        }
+
+        let mut user = User::default();
+
+        assert_eq!(user.try_set_value(User::AGE, Some("25")), Ok(()));
+        assert_eq!(user.age, 25);
+
+        assert_eq!(
+            user.try_set_value(User::AGE, None),
+            Err(ReflectError {
+                type_name: "User",
+                field:     "age".to_string(),
+                input:     None,
+                kind:      ReflectErrorKind::MissingValue,
+            })
+        );
+
+        let err = user.try_set_value(User::IS_POROS, Some("yes")).unwrap_err();
+        assert_eq!(err.field, "is_poros");
+        assert_eq!(err.input.as_deref(), Some("yes"));
+        assert_eq!(
+            err.kind,
+            ReflectErrorKind::Parse("Invalid value in bool: yes".to_string())
+        );
+        assert_eq!(
+            err.to_string(),
+            r#"Invalid value of User.is_poros: "yes". Invalid value in bool: yes"#
+        );
+
+        assert!(matches!(
+            user.try_set_value(User::BIRTHDAY, Some("yesterday")),
+            Err(ReflectError {
+                kind: ReflectErrorKind::Parse(_),
+                ..
+            })
+        ));
+        assert_eq!(user.age, 25);
+
+        let err = user.try_value_by_path("nickname").unwrap_err();
+        assert_eq!(err.kind, ReflectErrorKind::UnknownField);
+        assert_eq!(err.to_string(), "Unknown field nickname of User");
+
+        let mut event = Event::default();
+
+        assert_eq!(
+            event.try_set_value(Event::DAY, None).unwrap_err().kind,
+            ReflectErrorKind::MissingValue
+        );
+        assert!(event.try_set_value(Event::DAY, Some("2024-12-24")).is_err());
+        assert_eq!(event.try_set_value(Event::DAY, Some("24.12.2024")), Ok(()));
+
+        assert_eq!(
+            event.try_set_value_by_path("address.street", Some("Main")).unwrap_err(),
+            ReflectError {
+                type_name: "Event",
+                field:     "address.street".to_string(),
+                input:     Some("Main".to_string()),
+                kind:      ReflectErrorKind::UnknownField,
+            }
+        );
+        assert_eq!(
+            event.try_set_value_by_path("address.city", Some("Vilnius")),
+            Ok(())
+        );
+        assert_eq!(event.try_value_by_path("address.city").as_deref(), Ok("Vilnius"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value of User.age: \"old\"")]
+    fn set_value_panics() {
+        User::default().set_value(User::AGE, Some("old"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown field name of User")]
+    fn get_unknown_field_panics() {
+        let field = reflected::Field {
+            index: User::FIELD_COUNT,
+            ..User::NAME
+        };
+
+        User::default().get(field);
+    }
+
+    #[test]
+    fn typed_fields() {
+        use reflected::TypedField;
+
+        use crate::UserFields;
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Wrapper<T>(T);
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Point {
+            x:       i32,
+            x_typed: i32,
+        }
+
+        fn sorted_by<V: Ord>(mut users: Vec<User>, field: TypedField<User, V>) -> Vec<String> {
+            users.sort_by(|a, b| field.get(a).cmp(field.get(b)));
+            users.into_iter().map(|user| user.name).collect()
+        }
+
+        let users: Vec<User> = [("b", 30), ("c", 10), ("a", 20)]
+            .into_iter()
+            .map(|(name, age)| User {
+                name: name.to_string(),
+                age,
+                ..Default::default()
+            })
+            .collect();
+
+        assert_eq!(sorted_by(users.clone(), UserFields::NAME), ["a", "b", "c"]);
+        assert_eq!(sorted_by(users.clone(), UserFields::AGE), ["c", "a", "b"]);
+
+        let mut user = users[0].clone();
+
+        assert_eq!(UserFields::NAME.get(&user), "b");
+        assert_eq!(UserFields::STR_OPT.get(&user), &None);
+
+        UserFields::NAME.get_mut(&mut user).push('!');
+        UserFields::STR_OPT.set(&mut user, Some("x".to_string()));
+        UserFields::HEIGHT.set(&mut user, 1.5);
+
+        assert_eq!(user.name, "b!");
+        assert_eq!(user.str_opt.as_deref(), Some("x"));
+        assert_eq!(user.height, 1.5);
+
+        assert_eq!(UserFields::NAME.field, User::NAME);
+        assert!(UserFields::STR_OPT.is_optional());
+        assert_eq!(UserFields::HEIGHT.name, "height");
+
+        let changed: Vec<&str> = [UserFields::NAME, UserFields::EMAIL]
+            .iter()
+            .filter(|field| field.get(&user) != field.get(&users[0]))
+            .map(|field| field.name)
+            .collect();
+        assert_eq!(changed, ["name"]);
+
+        let mut wrapper = Wrapper(5_i64);
+        WrapperFields::<i64>::_0.set(&mut wrapper, 7);
+        assert_eq!(*WrapperFields::<i64>::_0.get(&wrapper), 7);
+
+        let mut point = Point::default();
+        PointFields::X.set(&mut point, 1);
+        PointFields::X_TYPED.set(&mut point, 2);
+        assert_eq!(
+            point,
+            Point {
+                x:       1,
+                x_typed: 2,
+            }
+        );
+        assert_eq!(Point::X_TYPED.name, "x_typed");
+    }
+
+    #[test]
+    fn record_maps() {
+        use std::collections::BTreeMap;
+
+        use reflected::{MapPolicy, MissingKeys, ReflectErrorKind, UnknownKeys};
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Product {
+            name:  String,
+            price: Decimal,
+            stock: Option<i32>,
+        }
+
+        let product = Product {
+            name:  "Tea".to_string(),
+            price: Decimal::new(250, 2),
+            stock: None,
+        };
+
+        let map = product.to_map();
+
+        assert_eq!(
+            map,
+            BTreeMap::from([
+                ("name", "Tea".to_string()),
+                ("price", "2.50".to_string()),
+                ("stock", "NULL".to_string()),
+            ])
+        );
+        assert_eq!(Product::from_map(&map), Ok(product.clone()));
+
+        let partial = BTreeMap::from([("name", "Tea".to_string()), ("stock", "5".to_string())]);
+
+        assert_eq!(
+            Product::from_map(&partial),
+            Ok(Product {
+                name:  "Tea".to_string(),
+                price: Decimal::ZERO,
+                stock: Some(5),
+            })
+        );
+
+        let err = Product::from_map_with(&partial, MapPolicy::STRICT).unwrap_err();
+        assert_eq!(err.field, "price");
+        assert_eq!(err.kind, ReflectErrorKind::MissingValue);
+
+        let mut extra = map.clone();
+        extra.insert("color", "green".to_string());
+
+        let err = Product::from_map(&extra).unwrap_err();
+        assert_eq!(err.field, "color");
+        assert_eq!(err.input.as_deref(), Some("green"));
+        assert_eq!(err.kind, ReflectErrorKind::UnknownField);
+
+        assert_eq!(
+            Product::from_map_with(&extra, MapPolicy::LENIENT),
+            Ok(product.clone())
+        );
+        assert_eq!(
+            Product::from_map_with(
+                &extra,
+                MapPolicy {
+                    unknown: UnknownKeys::Ignore,
+                    missing: MissingKeys::Error,
+                }
+            ),
+            Ok(product.clone())
+        );
+
+        let invalid = BTreeMap::from([("price", "cheap".to_string())]);
+        assert!(matches!(
+            Product::from_map(&invalid).unwrap_err().kind,
+            ReflectErrorKind::Parse(_)
+        ));
+
+        assert_eq!(
+            Product::from_positional(&[Some("Tea"), Some("2.50"), None]),
+            Ok(product)
+        );
+        assert_eq!(
+            Product::from_positional(&[Some("Tea"), Some("2.50")]).unwrap_err().field,
+            "stock"
+        );
+        assert_eq!(
+            Product::from_positional(&[Some("Tea"), Some("2.50"), None, Some("x")])
+                .unwrap_err()
+                .kind,
+            ReflectErrorKind::UnknownField
+        );
+        assert_eq!(
+            Product::from_positional(&[None, Some("1"), None]).unwrap_err().kind,
+            ReflectErrorKind::MissingValue
+        );
+    }
+
+    #[test]
+    fn field_lookup() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        #[reflected(rename_all = "camelCase")]
+        struct Query {
+            #[reflected(alias = "q", alias = "search")]
+            search_text: String,
+            page_size:   Option<i32>,
+            #[reflected(skip_fields)]
+            internal:    bool,
+        }
+
+        assert_eq!(Query::try_field_by_name("searchText"), Some(Query::SEARCH_TEXT));
+        assert_eq!(Query::try_field_by_name("q"), Some(Query::SEARCH_TEXT));
+        assert_eq!(Query::try_field_by_name("search"), Some(Query::SEARCH_TEXT));
+        assert_eq!(Query::try_field_by_name("pageSize"), Some(Query::PAGE_SIZE));
+        assert_eq!(Query::try_field_by_name("pagesize"), None);
+        assert_eq!(Query::try_field_by_name("internal"), None);
+        assert_eq!(Query::try_field_by_name(""), None);
+
+        assert_eq!(
+            Query::try_field_by_name_ignore_case("PAGESIZE"),
+            Some(Query::PAGE_SIZE)
+        );
+        assert_eq!(
+            Query::try_field_by_name_ignore_case("Q"),
+            Some(Query::SEARCH_TEXT)
+        );
+        assert_eq!(Query::try_field_by_name_ignore_case("page_size"), None);
+        assert_eq!(Query::try_field_by_name_ignore_case("searchTextLonger"), None);
+        assert_eq!(Query::try_field_by_name_ignore_case(""), None);
+
+        #[derive(Reflected, Default)]
+        struct Cased {
+            #[reflected(rename = "Id")]
+            first:  i32,
+            #[reflected(rename = "id")]
+            second: i32,
+            #[reflected(rename = "Größe")]
+            size:   i32,
+        }
+
+        assert_eq!(Cased::try_field_by_name("id"), Some(Cased::SECOND));
+        assert_eq!(Cased::try_field_by_name_ignore_case("id"), Some(Cased::FIRST));
+        assert_eq!(Cased::try_field_by_name_ignore_case("gRößE"), Some(Cased::SIZE));
+        assert_eq!(Cased::try_field_by_name_ignore_case("GRÖSSE"), None);
+
+        assert_eq!(Query::field_by_name("q"), Query::SEARCH_TEXT);
+        assert_eq!(User::try_field_by_name("email"), Some(User::EMAIL));
+        assert_eq!(User::try_field_by_name("mail"), None);
+
+        let mut query = Query::default();
+
+        for (key, value) in [("Q", "tea"), ("PageSize", "20"), ("unknown", "1")] {
+            if let Some(field) = Query::try_field_by_name_ignore_case(key) {
+                query.set_value(field, Some(value));
+            }
+        }
+
+        assert_eq!(query.search_text, "tea");
+        assert_eq!(query.page_size, Some(20));
+        assert_eq!(query.value_by_path("q"), "tea");
+    }
+
+    #[test]
+    fn field_index() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Row {
+            #[reflected(skip_fields)]
+            version: i32,
+            id:      i32,
+            name:    String,
+            note:    Option<String>,
+        }
+
+        const { assert!(User::FIELD_COUNT == 18) };
+        assert_eq!(User::fields().len(), User::FIELD_COUNT);
+
+        for (index, field) in User::fields().iter().enumerate() {
+            assert_eq!(field.index, index);
+            assert_eq!(User::field_at(index), Some(*field));
+        }
+
+        assert_eq!(User::field_at(User::FIELD_COUNT), None);
+
+        const { assert!(Row::FIELD_COUNT == 3) };
+        assert_eq!(Row::ID.index, 0);
+        assert_eq!(Row::NOTE.index, 2);
+        assert_eq!(Row::VERSION.index, 3);
+        assert_eq!(Row::field_at(3), None);
+
+        let mut row = Row::default();
+
+        for (index, value) in "5,Tea,NULL".split(',').enumerate() {
+            row.set_value(Row::field_at(index).unwrap(), Some(value));
+        }
+
+        row.set_value(Row::VERSION, Some("2"));
+
+        assert_eq!(
+            row,
+            Row {
+                version: 2,
+                id:      5,
+                name:    "Tea".to_string(),
+                note:    None,
+            }
+        );
+        assert_eq!(row.get_value(Row::VERSION), "2");
+
+        let line = (0..Row::FIELD_COUNT)
+            .map(|index| row.get_value(Row::field_at(index).unwrap()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        assert_eq!(line, "5,Tea,NULL");
+        assert_eq!(User::EMAIL.erased().index, User::EMAIL.index);
+    }
+
+    #[test]
+    fn date_format() {
+        #[derive(Default, Reflected)]
+        struct Event {
+            #[reflected(format = "%d.%m.%Y %H:%M")]
+            start: NaiveDateTime,
+            #[reflected(format = "%Y/%m/%d %H:%M:%S")]
+            end:   Option<NaiveDateTime>,
+        }
+
+        let mut event = Event::default();
+
+        event.set_value(Event::START, "24.12.2024 18:30".into());
+        event.set_value(Event::END, "2024/12/25 02:00:00".into());
+
+        assert_eq!(event.start.to_string(), "2024-12-24 18:30:00");
+        assert_eq!(event.get_value(Event::START), "24.12.2024 18:30");
+        assert_eq!(event.get_value(Event::END), "2024/12/25 02:00:00");
+
+        event.set_value(Event::END, None);
+
+        assert_eq!(event.get_value(Event::END), "NULL");
+    }
+
+    #[test]
+    fn reflected_enum() {
+        #[derive(Reflected, Copy, Clone, PartialEq, Debug)]
+        enum Status {
+            Active = 5,
+            Blocked = 10,
+        }
+
+        assert_eq!(Status::variants(), &[Status::Active, Status::Blocked]);
+        assert_eq!(Status::variant_names(), &["Active", "Blocked"]);
+
+        assert_eq!(Status::Active.variant_name(), "Active");
+        assert_eq!(Status::from_variant_name("Blocked"), Some(Status::Blocked));
+        assert_eq!(Status::from_variant_name("Deleted"), None);
+
+        assert_eq!(Status::Blocked.discriminant(), 10);
+        assert_eq!(Status::from_discriminant(5), Some(Status::Active));
+        assert_eq!(Status::from_discriminant(6), None);
+
+        assert_eq!(SomeEnum::B.to_reflected_string(), "B");
+        assert_eq!(Some(SomeEnum::B).to_reflected_string(), "B");
+        assert_eq!(None::<SomeEnum>.to_reflected_string(), "NULL");
+
+        assert_eq!("A".to_reflected_val(), Ok(SomeEnum::A));
+        assert_eq!("NULL".to_reflected_val(), Ok(None::<SomeEnum>));
+        assert!(ToReflectedVal::<SomeEnum>::to_reflected_val(&"C").is_err());
+    }
+
+    #[test]
+    fn optional_enum_and_custom() {
+        use reflected::{OptionalType, ReflectedValue, Type};
+
+        #[derive(Clone, Default, PartialEq, Debug)]
+        struct Code(String);
+
+        impl ReflectedValue for Code {
+            const TYPE: Type = Type::Text;
+
+            fn to_reflected_value(&self) -> String {
+                self.0.to_uppercase()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                Ok(Self(value.ok_or("Code can't be NULL")?.to_lowercase()))
+            }
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Member {
+            role:  Option<SomeEnum>,
+            code:  Option<Code>,
+            inner: Option<Option<i32>>,
+        }
+
+        assert_eq!(Member::ROLE.tp, Type::Optional(OptionalType::Enum));
+        assert!(Member::ROLE.is_enum());
+        assert!(Member::ROLE.is_optional());
+        assert!(Member::CODE.is_text());
+        assert!(Member::CODE.is_optional());
+        assert_eq!(Member::INNER.tp, Type::Integer.to_optional());
+
+        let mut member = Member::default();
+
+        assert_eq!(member.get_value(Member::ROLE), "NULL");
+        assert_eq!(member.get_value(Member::CODE), "NULL");
+
+        member.set_value(Member::ROLE, Some("B"));
+        member.set_value(Member::CODE, Some("Lt"));
+
+        assert_eq!(member.role, Some(SomeEnum::B));
+        assert_eq!(member.code, Some(Code("lt".to_string())));
+        assert_eq!(member.get_value(Member::ROLE), "B");
+        assert_eq!(member.get_value(Member::CODE), "LT");
+
+        member.set_value(Member::ROLE, None);
+        member.set_value(Member::CODE, Some("NULL"));
+
+        assert_eq!(member, Member::default());
+        assert_eq!(bound_count(member), 3);
+
+        let random = Member::random();
+        assert_eq!(random.role, None);
+        assert_eq!(random.code, None);
+    }
+
+    #[test]
+    fn qualified_types() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Event {
+            created:  chrono::NaiveDateTime,
+            finished: ::std::option::Option<chrono::NaiveDateTime>,
+            price:    core::option::Option<rust_decimal::Decimal>,
+            count:    i64,
+        }
+
+        assert_eq!(Event::CREATED.type_name, "chrono::NaiveDateTime");
+        assert!(Event::CREATED.is_date());
+        assert!(!Event::CREATED.is_optional());
+
+        assert_eq!(Event::FINISHED.type_name, "chrono::NaiveDateTime");
+        assert!(Event::FINISHED.is_date());
+        assert!(Event::FINISHED.is_optional());
+
+        assert_eq!(Event::PRICE.type_name, "rust_decimal::Decimal");
+        assert!(Event::PRICE.is_decimal());
+        assert!(Event::PRICE.is_optional());
+
+        assert_eq!(Event::COUNT.type_name, "i64");
+        assert!(Event::COUNT.is_integer());
+
+        let mut event = Event::default();
+
+        event.set_value(Event::FINISHED, Some("2024-05-01 10:00:00"));
+        event.set_value(Event::PRICE, Some("10.5"));
+
+        assert_eq!(event.get_value(Event::FINISHED), "2024-05-01T10:00:00");
+
+        event.set_value(Event::FINISHED, Some("2024-05-01T10:00:00.5"));
+        assert_eq!(event.get_value(Event::FINISHED), "2024-05-01T10:00:00.500");
+        assert_eq!(event.get_value(Event::PRICE), "10.5");
+    }
+
+    #[test]
+    fn type_aliases() {
+        use reflected::{ReflectedValue, Type, Value};
+
+        type UserId = i64;
+        type Money = Decimal;
+
+        /// Registered as `Cents = "Integer"` in `Cargo.toml`
+        #[derive(Clone, Copy, Default, PartialEq, Debug)]
+        struct Cents(u32);
+
+        impl ReflectedValue for Cents {
+            const TYPE: Type = Type::Text;
+
+            fn to_reflected_value(&self) -> String {
+                self.0.to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                u32::from_reflected_value(value).map(Self)
+            }
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Order {
+            #[reflected(type = "Integer")]
+            user_id:  UserId,
+            #[reflected(type = "Integer")]
+            buyer_id: Option<UserId>,
+            price:    Money,
+            tip:      Cents,
+            #[reflected(type = "Date")]
+            due:      String,
+        }
+
+        assert!(Order::USER_ID.is_integer());
+        assert!(Order::BUYER_ID.is_integer());
+        assert!(Order::BUYER_ID.is_optional());
+        assert!(Order::PRICE.is_decimal());
+        assert!(Order::TIP.is_integer());
+        assert!(Order::DUE.is_date());
+
+        let mut order = Order::default();
+
+        order.set_value(Order::USER_ID, Some("5"));
+        order.set_value(Order::BUYER_ID, Some("7"));
+        order.set_value(Order::PRICE, Some("10.5"));
+        order.set_value(Order::TIP, Some("250"));
+        order.set_value(Order::DUE, Some("2024-05-01T10:00:00"));
+
+        assert_eq!(
+            order,
+            Order {
+                user_id:  5,
+                buyer_id: Some(7),
+                price:    Decimal::from_str("10.5").unwrap(),
+                tip:      Cents(250),
+                due:      "2024-05-01T10:00:00".to_string(),
+            }
+        );
+
+        assert_eq!(order.get_value(Order::USER_ID), "5");
+        assert_eq!(order.get_value(Order::BUYER_ID), "7");
+        assert_eq!(order.get_value(Order::PRICE), "10.5");
+        assert_eq!(order.get_value(Order::TIP), "250");
+
+        // Overridden types drive typed values
+        assert_eq!(order.get(Order::TIP), Value::Int(250));
+        assert_eq!(
+            order.get(Order::DUE),
+            Value::Date(NaiveDateTime::from_str("2024-05-01T10:00:00").unwrap())
+        );
+
+        order.set(Order::TIP, Value::Int(300)).unwrap();
+        assert_eq!(order.tip, Cents(300));
+        assert!(order.set(Order::TIP, Value::Text("many".to_string())).is_err());
+
+        // Values must parse as overridden type
+        assert!(order.try_set_value(Order::DUE, Some("tomorrow")).is_err());
+        assert!(order.set(Order::DUE, Value::Text("tomorrow".to_string())).is_err());
+        assert_eq!(order.due, "2024-05-01T10:00:00");
+
+        let random = Order::random();
+        assert!(NaiveDateTime::from_str(&random.due).is_ok());
+    }
+
+    #[test]
+    fn custom_value() {
+        use std::net::{IpAddr, Ipv4Addr};
+
+        use reflected::{ReflectedValue, Type, Value};
+
+        #[derive(Clone, PartialEq, Debug)]
+        struct Ip(IpAddr);
+
+        impl Default for Ip {
+            fn default() -> Self {
+                Self(Ipv4Addr::LOCALHOST.into())
+            }
+        }
+
+        impl ReflectedValue for Ip {
+            const TYPE: Type = Type::Text;
+
+            fn to_reflected_value(&self) -> String {
+                self.0.to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                let value = value.ok_or("Ip can't be NULL")?;
+                value.parse().map(Self).map_err(|err| format!("Invalid ip {value}: {err}"))
+            }
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Session {
+            ip:    Ip,
+            proxy: Option<Ip>,
+            #[reflected(type = "Integer")]
+            port:  Port,
+        }
+
+        #[derive(Clone, Copy, Default, PartialEq, Debug)]
+        struct Port(u16);
+
+        impl ReflectedValue for Port {
+            const TYPE: Type = Type::Text;
+            const RANDOM: bool = true;
+
+            fn to_reflected_value(&self) -> String {
+                self.0.to_string()
+            }
+
+            fn from_reflected_value(value: Option<&str>) -> Result<Self, String> {
+                u16::from_reflected_value(value).map(Self)
+            }
+
+            fn bind_sqlx_value<'q, O>(
+                value: Option<&Self>,
+                query: reflected::SqlxQuery<'q, O>,
+            ) -> reflected::SqlxQuery<'q, O> {
+                query.bind(value.map(|port| i32::from(port.0)))
+            }
+        }
+
+        assert!(Session::IP.is_text());
+        assert!(Session::PROXY.is_text());
+        assert!(Session::PROXY.is_optional());
+        assert!(Session::PORT.is_integer());
+        assert_eq!(Session::default().get(Session::PORT), Value::Int(0));
+
+        let mut session = Session::default();
+
+        assert_eq!(session.get_value(Session::IP), "127.0.0.1");
+        assert_eq!(session.get_value(Session::PROXY), "NULL");
+
+        session.set_value(Session::IP, Some("10.0.0.1"));
+        session.set_value(Session::PROXY, Some("::1"));
+        session.set_value(Session::PORT, Some("8080"));
+
+        assert_eq!(session.ip, Ip(Ipv4Addr::new(10, 0, 0, 1).into()));
+        assert_eq!(session.get_value(Session::PROXY), "::1");
+        assert_eq!(session.get_value(Session::PORT), "8080");
+
+        session.set_value(Session::PROXY, None);
+        assert_eq!(session.proxy, None);
+
+        // `Ip` is bound as text, `Port` with its own `bind_sqlx_value`
+        assert_eq!(bound_count(session), 3);
+
+        // `Ip` can't be generated from `Type::Text`
+        let random = Session::random();
+        assert_eq!(random.ip, Ip::default());
+        assert_eq!(random.proxy, None);
+        assert!(random.port.0 < 1_000);
+    }
+
+    #[test]
+    fn uuid_fields() {
+        use uuid::Uuid;
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Account {
+            id:       Uuid,
+            owner_id: Option<Uuid>,
+        }
+
+        assert!(Account::ID.is_uuid());
+        assert!(Account::OWNER_ID.is_uuid());
+        assert!(Account::OWNER_ID.is_optional());
+
+        let id = Uuid::from_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        let mut account = Account::default();
+
+        account.set_value(Account::ID, Some("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert_eq!(account.id, id);
+        assert_eq!(
+            account.get_value(Account::ID),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(account.get_value(Account::OWNER_ID), "NULL");
+
+        assert_eq!(id.to_reflected_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!("NULL".to_reflected_val(), Ok(None::<Uuid>));
+
+        let random = Account::random();
+        assert_ne!(random.id, Uuid::nil());
+    }
+
+    #[test]
+    fn integer_kinds() {
+        use std::num::{NonZeroI64, NonZeroU32};
+
+        use reflected::IntegerKind;
+
+        #[derive(Reflected, Clone, PartialEq, Debug)]
+        struct Counter {
+            small: i16,
+            // Postgres has no 128 bit columns
+            #[reflected(skip_bind)]
+            wide:  i128,
+            #[reflected(skip_bind)]
+            huge:  u128,
+            step:  NonZeroU32,
+            limit: Option<NonZeroI64>,
+        }
+
+        impl Default for Counter {
+            fn default() -> Self {
+                Self {
+                    small: 0,
+                    wide:  0,
+                    huge:  0,
+                    step:  NonZeroU32::MIN,
+                    limit: None,
+                }
+            }
+        }
+
+        assert!(Counter::WIDE.is_integer());
+        assert!(Counter::LIMIT.is_integer());
+        assert_eq!(Counter::SMALL.integer_kind, Some(IntegerKind::new(16, true)));
+        assert_eq!(Counter::WIDE.integer_kind, Some(IntegerKind::new(128, true)));
+        assert_eq!(
+            Counter::LIMIT.integer_kind,
+            Some(IntegerKind::new(64, true).non_zero())
+        );
+        assert_eq!(Counter::STEP.integer_kind.unwrap().min(), 1);
+        assert_eq!(Counter::HUGE.integer_kind.unwrap().max(), u128::MAX);
+
+        let mut counter = Counter::default();
+
+        counter.set_value(Counter::WIDE, Some("-170141183460469231731687303715884105728"));
+        counter.set_value(Counter::HUGE, Some("340282366920938463463374607431768211455"));
+        counter.set_value(Counter::STEP, Some("5"));
+        counter.set_value(Counter::LIMIT, Some("-7"));
+
+        assert_eq!(counter.wide, i128::MIN);
+        assert_eq!(counter.huge, u128::MAX);
+        assert_eq!(counter.step.get(), 5);
+        assert_eq!(counter.limit, NonZeroI64::new(-7));
+        assert_eq!(counter.get_value(Counter::HUGE), u128::MAX.to_string());
+        assert_eq!(counter.get_value(Counter::LIMIT), "-7");
+
+        assert_eq!(NonZeroU32::new(3).to_reflected_string(), "3");
+        assert_eq!("NULL".to_reflected_val(), Ok(None::<NonZeroU32>));
+        assert!(ToReflectedVal::<NonZeroU32>::to_reflected_val(&"0").is_err());
+
+        assert_eq!(bound_count(counter), 3);
+
+        for _ in 0..100 {
+            let random = Counter::random();
+            assert_ne!(random.step.get(), 0);
+            assert_ne!(random.limit.map(NonZeroI64::get), Some(0));
+        }
+    }
+
+    #[test]
+    fn json_fields() {
+        use serde_json::{Value, json};
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Event {
+            metadata: Value,
+            extra:    Option<Value>,
+        }
+
+        assert!(Event::METADATA.is_json());
+        assert!(Event::EXTRA.is_json());
+        assert!(Event::EXTRA.is_optional());
+
+        let mut event = Event::default();
+
+        assert_eq!(event.get_value(Event::METADATA), "null");
+        assert_eq!(event.get_value(Event::EXTRA), "NULL");
+
+        event.set_value(Event::METADATA, Some(r#"{ "tags": ["a", "b"], "count": 2 }"#));
+        assert_eq!(event.metadata, json!({"tags": ["a", "b"], "count": 2}));
+        assert_eq!(
+            event.get_value(Event::METADATA),
+            r#"{"count":2,"tags":["a","b"]}"#
+        );
+
+        event.set_value(Event::EXTRA, Some("[1,null]"));
+        assert_eq!(event.extra, Some(json!([1, null])));
+
+        event.set_value(Event::EXTRA, None);
+        assert_eq!(event.extra, None);
+
+        assert_eq!("NULL".to_reflected_val(), Ok(None::<Value>));
+        assert!(ToReflectedVal::<Value>::to_reflected_val(&"{").is_err());
+
+        let random = Event::random();
+        assert!(random.metadata.is_object());
+    }
+
+    #[test]
+    fn chrono_types() {
+        use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Meeting {
+            day:      NaiveDate,
+            start:    NaiveTime,
+            created:  DateTime<Utc>,
+            local:    Option<DateTime<FixedOffset>>,
+            #[reflected(format = "%d.%m.%Y")]
+            deadline: Option<NaiveDate>,
+            #[reflected(format = "%Y-%m-%d %H:%M")]
+            updated:  DateTime<Utc>,
+        }
+
+        assert!(Meeting::DAY.is_naive_date());
+        assert!(Meeting::START.is_naive_time());
+        assert!(Meeting::CREATED.is_date_time());
+        assert!(Meeting::LOCAL.is_date_time());
+        assert!(Meeting::LOCAL.is_optional());
+        assert!(!Meeting::DAY.is_date());
+
+        let mut meeting = Meeting::default();
+
+        meeting.set_value(Meeting::DAY, Some("2024-05-01"));
+        meeting.set_value(Meeting::START, Some("10:30:00"));
+        meeting.set_value(Meeting::CREATED, Some("2024-05-01T10:30:00+02:00"));
+        meeting.set_value(Meeting::LOCAL, Some("2024-05-01T10:30:00+02:00"));
+        meeting.set_value(Meeting::DEADLINE, Some("15.05.2024"));
+        meeting.set_value(Meeting::UPDATED, Some("2024-05-02 12:00"));
+
+        assert_eq!(meeting.day, NaiveDate::from_ymd_opt(2024, 5, 1).unwrap());
+        assert_eq!(meeting.start, NaiveTime::from_hms_opt(10, 30, 0).unwrap());
+
+        assert_eq!(meeting.get_value(Meeting::DAY), "2024-05-01");
+        assert_eq!(meeting.get_value(Meeting::START), "10:30:00");
+        assert_eq!(meeting.get_value(Meeting::CREATED), "2024-05-01T08:30:00+00:00");
+        assert_eq!(meeting.get_value(Meeting::LOCAL), "2024-05-01T10:30:00+02:00");
+        assert_eq!(meeting.get_value(Meeting::DEADLINE), "15.05.2024");
+        assert_eq!(meeting.get_value(Meeting::UPDATED), "2024-05-02 12:00");
+
+        assert!(ToReflectedVal::<DateTime<Utc>>::to_reflected_val(&"2024-05-01 10:30").is_err());
+
+        for _ in 0..10 {
+            let random = Meeting::random();
+            assert_ne!(random.created, DateTime::<Utc>::default());
+            assert_ne!(random.updated, DateTime::<Utc>::default());
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct File {
+            hash:      [u8; 4],
+            #[reflected(encoding = "base64")]
+            content:   Vec<u8>,
+            #[reflected(encoding = "base64")]
+            thumbnail: Option<Vec<u8>>,
+            signature: Option<Vec<u8>>,
+        }
+
+        assert!(File::HASH.is_bytes());
+        assert!(File::CONTENT.is_bytes());
+        assert!(File::THUMBNAIL.is_bytes());
+        assert!(File::THUMBNAIL.is_optional());
+        assert_eq!(File::HASH.type_name, "[u8; 4]");
+        assert_eq!(File::CONTENT.type_name, "Vec<u8>");
+
+        let mut file = File::default();
+
+        file.set_value(File::HASH, Some("deadbeef"));
+        file.set_value(File::CONTENT, Some("aGVsbG8="));
+        file.set_value(File::THUMBNAIL, Some("AA//"));
+        file.set_value(File::SIGNATURE, Some("00ff"));
+
+        assert_eq!(
+            file,
+            File {
+                hash:      [0xde, 0xad, 0xbe, 0xef],
+                content:   b"hello".to_vec(),
+                thumbnail: Some(vec![0, 15, 255]),
+                signature: Some(vec![0, 255]),
+            }
+        );
+
+        assert_eq!(file.get_value(File::HASH), "deadbeef");
+        assert_eq!(file.get_value(File::CONTENT), "aGVsbG8=");
+        assert_eq!(file.get_value(File::THUMBNAIL), "AA//");
+        assert_eq!(file.get_value(File::SIGNATURE), "00ff");
+
+        file.set_value(File::THUMBNAIL, None);
+        assert_eq!(file.get_value(File::THUMBNAIL), "NULL");
+
+        let random = File::random();
+        assert_eq!(random.hash, [0; 4]);
+    }
+
+    #[test]
+    fn lists() {
+        use std::collections::HashSet;
+
+        use reflected::Type;
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Post {
+            tags:    Vec<String>,
+            scores:  Option<Vec<i64>>,
+            // Postgres has no arrays of arrays
+            #[reflected(skip_bind)]
+            matrix:  Vec<Vec<f64>>,
+            rgb:     [i32; 3],
+            readers: HashSet<SomeEnum>,
+            labels:  Option<HashSet<String>>,
+            topics:  Topics,
+        }
+
+        type Topics = HashSet<String>;
+
+        assert!(Post::TAGS.is_list());
+        assert_eq!(Post::TAGS.tp, Type::List(&Type::Text));
+        assert!(Post::SCORES.is_optional());
+        assert_eq!(Post::SCORES.list_element(), Some(Type::Integer));
+        assert_eq!(Post::MATRIX.tp, Type::List(&Type::List(&Type::Float)));
+        assert_eq!(Post::RGB.tp, Type::List(&Type::Integer));
+        assert_eq!(Post::READERS.list_element(), Some(Type::Enum));
+        assert_eq!(Post::TAGS.type_name, "Vec<String>");
+
+        let mut post = Post::default();
+
+        post.set_value(Post::TAGS, Some(r#"["rust", "with \"quotes\""]"#));
+        post.set_value(Post::SCORES, Some("[1, 2, 3]"));
+        post.set_value(Post::MATRIX, Some("[[1.5], []]"));
+        post.set_value(Post::RGB, Some("[255, 0, 10]"));
+        post.set_value(Post::READERS, Some(r#"["B", "A", "B"]"#));
+
+        assert_eq!(
+            post,
+            Post {
+                tags:    vec!["rust".to_string(), "with \"quotes\"".to_string()],
+                scores:  Some(vec![1, 2, 3]),
+                matrix:  vec![vec![1.5], vec![]],
+                rgb:     [255, 0, 10],
+                readers: [SomeEnum::A, SomeEnum::B].into_iter().collect(),
+                labels:  None,
+                topics:  HashSet::new(),
+            }
+        );
+
+        assert_eq!(post.get_value(Post::TAGS), r#"["rust","with \"quotes\""]"#);
+        assert_eq!(post.get_value(Post::SCORES), "[1,2,3]");
+        assert_eq!(post.get_value(Post::MATRIX), "[[1.5],[]]");
+        assert_eq!(post.get_value(Post::RGB), "[255,0,10]");
+        assert_eq!(post.get_value(Post::READERS), r#"["A","B"]"#);
+
+        post.set_value(Post::SCORES, None);
+        assert_eq!(post.get_value(Post::SCORES), "NULL");
+
+        post.matrix = vec![vec![f64::INFINITY, 0.5]];
+        assert_eq!(post.get_value(Post::MATRIX), r#"[["inf",0.5]]"#);
+        post.set_value(Post::MATRIX, Some(r#"[["-inf"]]"#));
+        assert_eq!(post.matrix, vec![vec![f64::NEG_INFINITY]]);
+
+        post.set_value(Post::LABELS, Some(r#"["new"]"#));
+        assert_eq!(post.labels, Some(["new".to_string()].into_iter().collect()));
+
+        assert_eq!(bound_count(post.clone()), 6);
+
+        // `labels` and `topics` sets are bound as arrays like `tags`. Enum
+        // `readers` as JSON text
+        let arguments = format!("{:?}", bound_arguments(post));
+        assert_eq!(arguments.matches("PgTypeInfo(TextArray)").count(), 3);
+        assert_eq!(arguments.matches("PgTypeInfo(Text)").count(), 1);
+    }
+
+    #[test]
+    fn maps() {
+        use std::collections::{BTreeMap, HashMap};
+
+        use reflected::Type;
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Settings {
+            options: HashMap<String, String>,
+            limits:  BTreeMap<String, i64>,
+            extra:   Option<HashMap<String, Option<bool>>>,
+        }
+
+        assert!(Settings::OPTIONS.is_map());
+        assert_eq!(Settings::LIMITS.map_types(), Some((Type::Text, Type::Integer)));
+        assert!(Settings::EXTRA.is_map());
+        assert!(Settings::EXTRA.is_optional());
+
+        let mut settings = Settings::default();
+
+        assert_eq!(settings.get_value(Settings::OPTIONS), "{}");
+        assert_eq!(settings.get_value(Settings::EXTRA), "NULL");
+
+        settings.set_value(Settings::OPTIONS, Some(r#"{"theme": "dark", "lang": "en"}"#));
+        settings.set_value(Settings::LIMITS, Some(r#"{"b":2,"a":1}"#));
+
+        assert_eq!(settings.options["theme"], "dark");
+        assert_eq!(
+            settings.get_value(Settings::OPTIONS),
+            r#"{"lang":"en","theme":"dark"}"#
+        );
+        assert_eq!(settings.get_value(Settings::LIMITS), r#"{"a":1,"b":2}"#);
+
+        assert_eq!(settings.value_by_path("options.theme"), "dark");
+        assert_eq!(settings.value_by_path("limits.b"), "2");
+        assert_eq!(settings.value_by_path("limits.c"), "NULL");
+        assert_eq!(settings.value_by_path("extra.a"), "NULL");
+
+        settings.set_value_by_path("options.theme", Some("light"));
+        settings.set_value_by_path("limits.c", Some("3"));
+        settings.set_value_by_path("limits.a", None);
+
+        assert_eq!(settings.options["theme"], "light");
+        assert_eq!(settings.get_value(Settings::LIMITS), r#"{"b":2,"c":3}"#);
+
+        settings.set_value(Settings::EXTRA, Some(r#"{"x":true,"y":null,"z":0}"#));
+        assert_eq!(
+            settings.extra,
+            Some(
+                [
+                    ("x".to_string(), Some(true)),
+                    ("y".to_string(), None),
+                    ("z".to_string(), Some(false))
+                ]
+                .into_iter()
+                .collect()
+            )
+        );
+        assert_eq!(settings.value_by_path("extra.x"), "1");
+
+        settings.set_value_by_path("extra.y", Some("1"));
+        assert_eq!(settings.extra.as_ref().unwrap()["y"], Some(true));
+        assert!(settings.get_value(Settings::EXTRA).contains(r#""y":true"#));
+
+        // Maps are bound as JSON text
+        assert_eq!(bound_count(settings), 3);
+
+        let random = Settings::random();
+        assert!(random.options.is_empty());
+    }
+
+    #[test]
+    fn nested_structs() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Country {
+            code: String,
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Address {
+            city:    String,
+            zip:     Option<i32>,
+            country: Country,
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Customer {
+            name:    String,
+            address: Address,
+            billing: Option<Address>,
+        }
+
+        assert!(Customer::ADDRESS.is_struct());
+        assert!(Customer::BILLING.is_struct());
+        assert!(Customer::BILLING.is_optional());
+
+        let address = Customer::ADDRESS.struct_type().unwrap();
+        assert_eq!(address.name, "Address");
+        assert_eq!(
+            address.fields(),
+            Address::fields().iter().map(|f| f.erased()).collect::<Vec<_>>()
+        );
+        assert!(address.field("country").unwrap().is_struct());
+
+        let mut paths = vec![];
+        Customer::walk_fields(|path, _| paths.push(path.to_string()));
+        assert_eq!(
+            paths,
+            [
+                "name",
+                "address",
+                "address.city",
+                "address.zip",
+                "address.country",
+                "address.country.code",
+                "billing",
+                "billing.city",
+                "billing.zip",
+                "billing.country",
+                "billing.country.code",
+            ]
+        );
+
+        let mut customer = Customer::default();
+
+        customer.set_value_by_path("name", Some("Alex"));
+        customer.set_value_by_path("address.city", Some("Vilnius"));
+        customer.set_value_by_path("address.zip", Some("1000"));
+        customer.set_value_by_path("address.country.code", Some("LT"));
+
+        assert_eq!(
+            customer,
+            Customer {
+                name:    "Alex".to_string(),
+                address: Address {
+                    city:    "Vilnius".to_string(),
+                    zip:     Some(1000),
+                    country: Country {
+                        code: "LT".to_string(),
+                    },
+                },
+                billing: None,
+            }
+        );
+
+        assert_eq!(customer.value_by_path("address.city"), "Vilnius");
+        assert_eq!(customer.value_by_path("address.country.code"), "LT");
+        assert_eq!(customer.value_by_path("billing.city"), "NULL");
+        assert_eq!(
+            customer.get_value(Customer::ADDRESS),
+            r#"{"city":"Vilnius","zip":1000,"country":{"code":"LT"}}"#
+        );
+        assert_eq!(customer.get_value(Customer::BILLING), "NULL");
+
+        customer.set_value(Customer::BILLING, Some(r#"{"city":"Kaunas"}"#));
+        assert_eq!(customer.billing.as_ref().unwrap().city, "Kaunas");
+        assert_eq!(customer.value_by_path("billing.zip"), "NULL");
+
+        customer.set_value_by_path("address.zip", None);
+        assert_eq!(customer.address.zip, None);
+
+        // Nested structs are bound as JSON text
+        assert_eq!(bound_count(customer), 3);
+
+        let random = Customer::random();
+        assert_ne!(random.address.city, "");
+        assert_ne!(random.address.country.code, "");
+    }
+
+    #[test]
+    fn recursive_structs() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Node {
+            name:     String,
+            children: Vec<Node>,
+        }
+
+        let child = Node::CHILDREN.tp.list_element().unwrap().struct_type().unwrap();
+        assert_eq!(child.name, "Node");
+        assert_eq!(child.field("children").unwrap().tp, Node::CHILDREN.tp);
+        assert_eq!(
+            format!("{child:?}"),
+            r#"StructType { name: "Node", fields: ["name", "children"] }"#
+        );
+
+        let mut paths = vec![];
+        Node::walk_fields(|path, _| paths.push(path.to_string()));
+        assert_eq!(paths, ["name", "children"]);
+
+        let mut node = Node::default();
+        node.set_value(
+            Node::CHILDREN,
+            Some(r#"[{"name":"leaf","children":[{"name":"deep","children":[]}]}]"#),
+        );
+        assert_eq!(node.children[0].children[0].name, "deep");
+        assert_eq!(
+            node.get_value(Node::CHILDREN),
+            r#"[{"name":"leaf","children":[{"name":"deep","children":[]}]}]"#
+        );
+
+        let mut copy = Node::default();
+        copy.set(Node::CHILDREN, node.get(Node::CHILDREN)).unwrap();
+        assert_eq!(copy, node);
+    }
+
+    #[test]
+    fn formatted_collection_items() {
+        use std::collections::BTreeMap;
+
+        use reflected::Value;
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Inner {
+            #[reflected(format = "%d.%m.%Y")]
+            day: chrono::NaiveDate,
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Outer {
+            items:  Vec<Inner>,
+            by_key: BTreeMap<String, Inner>,
+        }
+
+        let inner = Inner {
+            day: chrono::NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+        };
+        let outer = Outer {
+            items:  vec![inner.clone()],
+            by_key: [("a".to_string(), inner.clone())].into(),
+        };
+
+        let item = Value::Struct(vec![("day".to_string(), Value::NaiveDate(inner.day))]);
+
+        assert_eq!(outer.get(Outer::ITEMS), Value::List(vec![item.clone()]));
+        assert_eq!(
+            outer.get(Outer::BY_KEY),
+            Value::Map([("a".to_string(), item.clone())].into())
+        );
+
+        let mut copy = Outer::default();
+        copy.set(Outer::ITEMS, outer.get(Outer::ITEMS)).unwrap();
+        copy.set(Outer::BY_KEY, outer.get(Outer::BY_KEY)).unwrap();
+        assert_eq!(copy, outer);
+
+        assert!(copy.set(Outer::ITEMS, item).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to get address.street of Customer")]
+    fn nested_unknown_path() {
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Address {
+            city: String,
+        }
+
+        #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+        struct Customer {
+            address: Address,
+        }
+
+        Customer::default().value_by_path("address.street");
+    }
+
     #[test]
     fn test_duration() {
         let _5_min = Duration::from(TimeDelta::minutes(5) + TimeDelta::seconds(25));